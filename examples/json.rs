@@ -1,8 +1,8 @@
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
-use std::thread;
 use std::time::Duration;
-use storyteller::{EventHandler, HandlerGuard};
+use std::{io, thread};
+use storyteller::{HandlerGuard, JsonLinesHandler};
 
 use storyteller::{
     event_channel, ChannelEventListener, ChannelReporter, EventListener, EventReporter,
@@ -16,29 +16,16 @@ enum Event {
     YouLose,
 }
 
-#[derive(Default)]
-struct JsonHandler;
-
-impl EventHandler for JsonHandler {
-    type Event = Event;
-
-    fn handle(&self, event: Self::Event) {
-        let serialized_event = serde_json::to_string(&event).unwrap();
-
-        println!("{}", serialized_event);
-    }
-}
-
-// See the test function `bar` in src/tests.rs for an example where the handler is a progress bar.
+// See the `progress` example for an example where the handler is a progress bar.
 fn main() {
     let (sender, receiver) = event_channel::<Event>();
 
-    // Handlers are implemented by you. Here you find one which writes jsonlines messages to stderr.
-    // This can be anything, for example a progress bar (see src/tests.rs for an example of this),
-    // a fake reporter which collects events for testing or maybe even a "MultiHandler<'h>" which
-    // consists of a Vec<&'h dyn EventHandler> and executes multiple handlers under the hood.
-    #[allow(clippy::default_constructed_unit_structs)]
-    let handler = JsonHandler::default();
+    // Handlers are usually implemented by you, but this one, which writes jsonlines messages to stdout,
+    // is included with the library when the `json` feature is enabled.
+    // A handler can be anything, for example a progress bar (see the `progress` example),
+    // a fake reporter which collects events for testing or maybe even a `FanOutHandler` which
+    // consists of multiple handlers and executes each of them under the hood.
+    let handler = JsonLinesHandler::new(io::stdout());
 
     // This one is included with the library. It just needs to be hooked up with a channel.
     let reporter = ChannelReporter::new(sender);
@@ -46,7 +33,7 @@ fn main() {
     // This one is also included with the library. It also needs to be hooked up with a channel.
     let listener = ChannelEventListener::new(receiver);
 
-    // Here we use the JsonHandler we defined above, in combination with the default `EventListener`
+    // Here we use the JsonLinesHandler we created above, in combination with the default `EventListener`
     // and  `ChannelEventListener` defined above.
     //
     // If we don't run the handler, we'll end up in an infinite loop, because our `reporter.disconnect()`
@@ -14,14 +14,14 @@ use storyteller::{
 //     The EventHandler must be defined by us, and can be found below.
 //     We also need to define our event type, which can also be found below.
 
-// See the test function `bar` in src/tests.rs for an example where the handler is a progress bar.
+// See the `progress` example for an example where the handler is a progress bar.
 fn main() {
     let (sender, receiver) = event_channel::<ExampleEvent>();
 
     // Handlers are implemented by you. Here you find one which writes jsonlines messages to stderr.
-    // This can be anything, for example a progress bar (see src/tests.rs for an example of this),
-    // a fake reporter which collects events for testing or maybe even a "MultiHandler<'h>" which
-    // consists of a Vec<&'h dyn EventHandler> and executes multiple handlers under the hood.
+    // This can be anything, for example a progress bar (see the `progress` example),
+    // a fake reporter which collects events for testing or maybe even a `FanOutHandler` which
+    // consists of multiple handlers and executes each of them under the hood.
     //
     // Its implementation can be found below.
     let handler = JsonHandler::default();
@@ -104,14 +104,37 @@ impl ExampleEvent {
 impl ExampleEvent {
     // Here we create some json by hand, so you can copy the example without importing other libraries, but you can also
     // replace all of this by, say `serde_json`, and derive a complete json output of your `Event` definition all at once (by design™ =)).
+    // With the `json` feature enabled, the `JsonLinesHandler` does exactly that, see the `json` example.
     pub fn to_json(&self) -> String {
         match self {
             Self::Event(event) => event.to_json(),
-            Self::Text(msg) => format!("{{ \"event\" : \"message\", \"value\" : \"{}\" }}", msg),
+            Self::Text(msg) => format!(
+                "{{ \"event\" : \"message\", \"value\" : \"{}\" }}",
+                escape_json(msg)
+            ),
         }
     }
 }
 
+// Escapes a string, so it can be used as a json string value.
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
 #[derive(Debug)]
 enum MyEvent {
     Increment,
@@ -121,8 +144,8 @@ enum MyEvent {
 impl MyEvent {
     pub fn to_json(&self) -> String {
         match self {
-            Self::Increment => format!("{{ \"event\" : \"increment\" }}"),
-            Self::Reset => format!("{{ \"event\" : \"reset\" }}"),
+            Self::Increment => "{ \"event\" : \"increment\" }".to_string(),
+            Self::Reset => "{ \"event\" : \"reset\" }".to_string(),
         }
     }
 }
@@ -164,7 +187,7 @@ impl EventHandler for JsonHandler {
     fn finish(&self) {
         let mut out = self.stream.lock().unwrap();
 
-        let message = format!("{{ \"event\" : \"program-finished\", \"success\" : true }}");
+        let message = "{ \"event\" : \"program-finished\", \"success\" : true }".to_string();
 
         let _ = writeln!(out, "{}", message);
         let _ = out.flush();
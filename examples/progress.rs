@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, EventListener, EventReporter,
+    HandlerGuard, ProgressHandler, ProgressMapping, ProgressUpdate,
+};
+
+enum Event {
+    Started { download: u32, size: u64 },
+    Progressed { download: u32, bytes: u64 },
+    Finished { download: u32 },
+    Failed { download: u32, reason: String },
+}
+
+// The mapping decides which progress bar each event updates, and how. Each download has its own
+// progress bar, so the downloads are shown as multiple bars.
+struct Downloads;
+
+impl ProgressMapping for Downloads {
+    type Event = Event;
+    type Task = u32;
+
+    fn map(&self, event: Self::Event) -> Vec<ProgressUpdate<u32>> {
+        match event {
+            Event::Started { download, size } => vec![
+                ProgressUpdate::SetLength {
+                    task: download,
+                    length: size,
+                },
+                ProgressUpdate::SetMessage {
+                    task: download,
+                    message: format!("download #{}", download).into(),
+                },
+            ],
+            Event::Progressed { download, bytes } => vec![ProgressUpdate::Increment {
+                task: download,
+                delta: bytes,
+            }],
+            Event::Finished { download } => vec![ProgressUpdate::Finish { task: download }],
+            Event::Failed { download, reason } => vec![
+                ProgressUpdate::Abandon { task: download },
+                ProgressUpdate::Println(
+                    format!("download #{} failed: {}", download, reason).into(),
+                ),
+            ],
+        }
+    }
+
+    fn new_bar(&self, _task: &u32) -> indicatif::ProgressBar {
+        let bar = indicatif::ProgressBar::new(0);
+        bar.set_style(
+            indicatif::ProgressStyle::with_template("{msg:>12} [{bar:40}] {pos}/{len}").unwrap(),
+        );
+        bar
+    }
+}
+
+fn main() {
+    let (sender, receiver) = event_channel::<Event>();
+
+    // The ProgressHandler is included with the library when the `indicatif` feature is enabled.
+    let handler = ProgressHandler::new(Downloads);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let guard = listener.run_handler(Arc::new(handler));
+
+    for download in 1..=3 {
+        reporter
+            .report_event(Event::Started { download, size: 10 })
+            .unwrap();
+    }
+
+    for step in 1..=10 {
+        thread::sleep(Duration::from_millis(100));
+
+        for download in 1..=3 {
+            // The second download fails halfway
+            if download == 2 && step > 5 {
+                continue;
+            }
+
+            reporter
+                .report_event(Event::Progressed { download, bytes: 1 })
+                .unwrap();
+        }
+
+        if step == 5 {
+            reporter
+                .report_event(Event::Failed {
+                    download: 2,
+                    reason: "connection reset".to_string(),
+                })
+                .unwrap();
+        }
+    }
+
+    reporter
+        .report_event(Event::Finished { download: 1 })
+        .unwrap();
+
+    // The third download isn't finished explicitly: since its bar reached its length, it's finished
+    // when the handler finishes
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+}
@@ -0,0 +1,75 @@
+use crate::EventHandler;
+use std::future::Future;
+
+/// An asynchronous variant of the [`EventHandler`], for handlers which perform I/O, such as
+/// sending a HTTP request or writing to a websocket, for each event.
+///
+/// Implementations can use `async fn` for both methods.
+///
+/// Requires the `async` feature. An [`AsyncEventListener`], such as the `TokioEventListener`,
+/// is required to run the handler.
+///
+/// The methods return `impl Future` in a trait, which requires Rust 1.75, so the `async`
+/// feature raises the MSRV of this crate from 1.60 to 1.75.
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`AsyncEventListener`]: crate::AsyncEventListener
+pub trait AsyncEventHandler: Send + Sync {
+    /// The type of event to be handled.
+    /// Usually the same type as you would send from a [`EventReporter`] to [`AsyncEventListener`].
+    ///
+    /// [`EventReporter`]: crate::EventReporter
+    /// [`AsyncEventListener`]: crate::AsyncEventListener
+    type Event;
+
+    /// Act upon some received event.
+    fn handle(&self, event: Self::Event) -> impl Future<Output = ()> + Send;
+
+    /// A final action which can be performed when no more events will be received, for example
+    /// when the message channel will be disconnected.
+    ///
+    /// It is up to the [`AsyncEventListener`] to call this method.
+    ///
+    /// [`AsyncEventListener`]: crate::AsyncEventListener
+    fn finish(&self) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+/// Turns an [`EventHandler`] into an [`AsyncEventHandler`].
+///
+/// The synchronous handler runs to completion on each call, so it should not block for long.
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`AsyncEventHandler`]: crate::AsyncEventHandler
+pub struct SyncHandlerAdapter<H> {
+    handler: H,
+}
+
+impl<H> SyncHandlerAdapter<H> {
+    /// Wrap a synchronous handler.
+    pub fn new(handler: H) -> Self {
+        Self { handler }
+    }
+
+    /// Take back the wrapped handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H> AsyncEventHandler for SyncHandlerAdapter<H>
+where
+    H: EventHandler,
+    H::Event: Send,
+{
+    type Event = H::Event;
+
+    async fn handle(&self, event: Self::Event) {
+        self.handler.handle(event)
+    }
+
+    async fn finish(&self) {
+        self.handler.finish()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::channel_reporter::channel::{EventRecvError, EventSendError};
+use std::time::Duration;
+
+pub(crate) fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    let (sender, receiver) = crossbeam_channel::unbounded();
+
+    (Sender(sender), Receiver(receiver))
+}
+
+pub(crate) fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let (sender, receiver) = crossbeam_channel::bounded(capacity);
+
+    (Sender(sender), Receiver(receiver))
+}
+
+pub(crate) struct Sender<T>(crossbeam_channel::Sender<T>);
+
+impl<T> Sender<T> {
+    pub(crate) fn send(&self, message: T) -> Result<(), EventSendError<T>> {
+        self.0
+            .send(message)
+            .map_err(|err| EventSendError::Disconnected(err.0))
+    }
+
+    pub(crate) fn try_send(&self, message: T) -> Result<(), EventSendError<T>> {
+        self.0.try_send(message).map_err(|err| match err {
+            crossbeam_channel::TrySendError::Full(message) => EventSendError::Full(message),
+            crossbeam_channel::TrySendError::Disconnected(message) => {
+                EventSendError::Disconnected(message)
+            }
+        })
+    }
+
+    pub(crate) fn send_timeout(
+        &self,
+        message: T,
+        timeout: Duration,
+    ) -> Result<(), EventSendError<T>> {
+        self.0
+            .send_timeout(message, timeout)
+            .map_err(|err| match err {
+                crossbeam_channel::SendTimeoutError::Timeout(message) => {
+                    EventSendError::Timeout(message)
+                }
+                crossbeam_channel::SendTimeoutError::Disconnected(message) => {
+                    EventSendError::Disconnected(message)
+                }
+            })
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+pub(crate) struct Receiver<T>(crossbeam_channel::Receiver<T>);
+
+impl<T> Receiver<T> {
+    pub(crate) fn recv(&self) -> Result<T, EventRecvError> {
+        self.0.recv().map_err(|_| EventRecvError)
+    }
+
+    pub(crate) fn try_recv(&self) -> Option<T> {
+        self.0.try_recv().ok()
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
@@ -0,0 +1,17 @@
+//! The channel implementation behind the `EventSender` and `EventReceiver`.
+//!
+//! A crossbeam channel is used when the `channel_reporter` feature is enabled. Otherwise, when
+//! only the `std_channel` feature is enabled, a `std::sync::mpsc` channel is used.
+//!
+//! Both backends provide the same `unbounded` and `bounded` functions, and `Sender` and `Receiver`
+//! types, with the same semantics.
+
+#[cfg(feature = "channel_reporter")]
+mod crossbeam;
+#[cfg(feature = "channel_reporter")]
+pub(crate) use self::crossbeam::{bounded, unbounded, Receiver, Sender};
+
+#[cfg(not(feature = "channel_reporter"))]
+mod std;
+#[cfg(not(feature = "channel_reporter"))]
+pub(crate) use self::std::{bounded, unbounded, Receiver, Sender};
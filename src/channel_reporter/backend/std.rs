@@ -0,0 +1,119 @@
+use crate::channel_reporter::channel::{EventRecvError, EventSendError};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// The interval at which a full channel is checked for room again, by `Sender::send_timeout`.
+const SEND_TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+pub(crate) fn unbounded<T>() -> (Sender<T>, Receiver<T>) {
+    let (sender, receiver) = mpsc::channel();
+
+    (
+        Sender(SenderKind::Unbounded(sender)),
+        Receiver(Arc::new(Mutex::new(receiver))),
+    )
+}
+
+pub(crate) fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let (sender, receiver) = mpsc::sync_channel(capacity);
+
+    (
+        Sender(SenderKind::Bounded(sender)),
+        Receiver(Arc::new(Mutex::new(receiver))),
+    )
+}
+
+pub(crate) struct Sender<T>(SenderKind<T>);
+
+enum SenderKind<T> {
+    Unbounded(mpsc::Sender<T>),
+    Bounded(mpsc::SyncSender<T>),
+}
+
+impl<T> Sender<T> {
+    pub(crate) fn send(&self, message: T) -> Result<(), EventSendError<T>> {
+        match &self.0 {
+            SenderKind::Unbounded(sender) => sender.send(message),
+            SenderKind::Bounded(sender) => sender.send(message),
+        }
+        .map_err(|err| EventSendError::Disconnected(err.0))
+    }
+
+    pub(crate) fn try_send(&self, message: T) -> Result<(), EventSendError<T>> {
+        match &self.0 {
+            SenderKind::Unbounded(sender) => sender
+                .send(message)
+                .map_err(|err| EventSendError::Disconnected(err.0)),
+            SenderKind::Bounded(sender) => sender.try_send(message).map_err(|err| match err {
+                mpsc::TrySendError::Full(message) => EventSendError::Full(message),
+                mpsc::TrySendError::Disconnected(message) => EventSendError::Disconnected(message),
+            }),
+        }
+    }
+
+    // `SyncSender::send_timeout` is not stable, so we check for room at a fixed interval instead
+    pub(crate) fn send_timeout(
+        &self,
+        mut message: T,
+        timeout: Duration,
+    ) -> Result<(), EventSendError<T>> {
+        // A timeout too large to be represented as a deadline never elapses, like in crossbeam.
+        let deadline = match Instant::now().checked_add(timeout) {
+            Some(deadline) => deadline,
+            None => return self.send(message),
+        };
+
+        loop {
+            match self.try_send(message) {
+                Err(EventSendError::Full(returned)) => {
+                    let now = Instant::now();
+
+                    if now >= deadline {
+                        return Err(EventSendError::Timeout(returned));
+                    }
+
+                    message = returned;
+                    thread::sleep(SEND_TIMEOUT_POLL_INTERVAL.min(deadline - now));
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            SenderKind::Unbounded(sender) => Self(SenderKind::Unbounded(sender.clone())),
+            SenderKind::Bounded(sender) => Self(SenderKind::Bounded(sender.clone())),
+        }
+    }
+}
+
+// A `std::sync::mpsc::Receiver` can not be cloned, so clones share it, and take turns receiving.
+pub(crate) struct Receiver<T>(Arc<Mutex<mpsc::Receiver<T>>>);
+
+impl<T> Receiver<T> {
+    pub(crate) fn recv(&self) -> Result<T, EventRecvError> {
+        self.0
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .recv()
+            .map_err(|_| EventRecvError)
+    }
+
+    // If another clone is receiving, the channel is about to have room anyway.
+    pub(crate) fn try_recv(&self) -> Option<T> {
+        self.0
+            .try_lock()
+            .ok()
+            .and_then(|receiver| receiver.try_recv().ok())
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
@@ -1,52 +1,566 @@
 //! Channels which can be used by the `ChannelReporter` and `ChannelEventListener`.
 
+use crate::channel_reporter::backend;
+use crate::FlushError;
 use std::fmt::Formatter;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::Duration;
 use std::{any, fmt};
 
 // --- Event channel variants
 
 /// A channel over which events are sent, from the `ChannelReporter` to the `ChannelEventListener`.
+///
+/// The channel is unbounded: events are queued until the handler gets to them. Use
+/// [`bounded_event_channel`] to put a limit on the number of queued events.
+///
+/// [`bounded_event_channel`]: crate::bounded_event_channel
 pub fn event_channel<Event>() -> (EventSender<Event>, EventReceiver<Event>) {
-    let (sender, receiver) = crossbeam_channel::unbounded::<Event>();
+    let (sender, receiver) = backend::unbounded::<Message<Event>>();
+    let depth = Arc::new(QueueDepth::new(None));
+    let barriers = Arc::new(Barriers::default());
 
-    (EventSender(sender), EventReceiver(receiver))
+    (
+        EventSender::new(
+            sender,
+            BackpressurePolicy::Block,
+            None,
+            depth.clone(),
+            barriers.clone(),
+        ),
+        EventReceiver::new(receiver, depth, barriers),
+    )
+}
+
+/// A channel over which events are sent, from the `ChannelReporter` to the `ChannelEventListener`,
+/// which holds at most `capacity` queued events.
+///
+/// When the channel is full, the given [`BackpressurePolicy`] decides what happens to the
+/// event which is being sent.
+///
+/// A channel with a `capacity` of `0` holds no events: an event is only sent when the listener
+/// is waiting for it. Such a channel is always full otherwise, and never holds an event which
+/// could be dropped, so [`BackpressurePolicy::DropOldest`] drops the event which is being sent
+/// instead, like [`BackpressurePolicy::DropNewest`].
+///
+/// [`BackpressurePolicy`]: crate::BackpressurePolicy
+/// [`BackpressurePolicy::DropOldest`]: crate::BackpressurePolicy::DropOldest
+/// [`BackpressurePolicy::DropNewest`]: crate::BackpressurePolicy::DropNewest
+pub fn bounded_event_channel<Event>(
+    capacity: usize,
+    policy: BackpressurePolicy,
+) -> (EventSender<Event>, EventReceiver<Event>) {
+    let (sender, receiver) = backend::bounded::<Message<Event>>(capacity);
+
+    let policy = match policy {
+        BackpressurePolicy::DropOldest if capacity == 0 => BackpressurePolicy::DropNewest,
+        policy => policy,
+    };
+
+    // To be able to drop the oldest event, the sender needs to be able to take it out of the
+    // channel.
+    let oldest = match policy {
+        BackpressurePolicy::DropOldest => Some(receiver.clone()),
+        _ => None,
+    };
+
+    let depth = Arc::new(QueueDepth::new(Some(capacity)));
+    let barriers = Arc::new(Barriers::default());
+
+    (
+        EventSender::new(sender, policy, oldest, depth.clone(), barriers.clone()),
+        EventReceiver::new(receiver, depth, barriers),
+    )
+}
+
+/// Decides what happens when an event is sent over a full [`bounded_event_channel`].
+///
+/// [`bounded_event_channel`]: crate::bounded_event_channel
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BackpressurePolicy {
+    /// Wait until the handler has made room for the event.
+    Block,
+    /// Discard the event which is being sent.
+    DropNewest,
+    /// Discard the oldest queued event, to make room for the event which is being sent.
+    ///
+    /// The sender holds on to a receiver of the channel, so sending an event does not fail when
+    /// the receiver of the listener has been dropped.
+    ///
+    /// A channel with a capacity of `0` never holds an event, so the event which is being sent
+    /// is discarded instead, like with [`BackpressurePolicy::DropNewest`].
+    ///
+    /// [`BackpressurePolicy::DropNewest`]: crate::BackpressurePolicy::DropNewest
+    DropOldest,
+    /// Refuse the event which is being sent, and return it as an [`EventSendError::Rejected`] error.
+    ///
+    /// [`EventSendError::Rejected`]: crate::EventSendError::Rejected
+    Fail,
+}
+
+/// What is sent over the channel: either an event, or a flush barrier.
+///
+/// A barrier is acknowledged once the listener takes it out of the channel. Since the listener
+/// handles events one at a time, in order, all events sent before the barrier have been
+/// handled by then.
+enum Message<T> {
+    Event(T),
+    Flush(Barrier),
+}
+
+impl<T> Message<T> {
+    /// Take back an event which could not be sent. Only events are sent with the send methods
+    /// of the `EventSender`, so barriers never come back this way.
+    fn into_event(self) -> T {
+        match self {
+            Self::Event(event) => event,
+            Self::Flush(_) => unreachable!("flush barriers are not sent as events"),
+        }
+    }
 }
 
 /// A sender, used by `ChannelReporter` and `ChannelEventListener`.
-pub struct EventSender<T>(crossbeam_channel::Sender<T>);
+pub struct EventSender<T> {
+    sender: backend::Sender<Message<T>>,
+    policy: BackpressurePolicy,
+    oldest: Option<backend::Receiver<Message<T>>>,
+    depth: Arc<QueueDepth>,
+    barriers: Arc<Barriers>,
+}
 
 impl<T> EventSender<T> {
+    fn new(
+        sender: backend::Sender<Message<T>>,
+        policy: BackpressurePolicy,
+        oldest: Option<backend::Receiver<Message<T>>>,
+        depth: Arc<QueueDepth>,
+        barriers: Arc<Barriers>,
+    ) -> Self {
+        Self {
+            sender,
+            policy,
+            oldest,
+            depth,
+            barriers,
+        }
+    }
+
+    /// Send a message, honouring the [`BackpressurePolicy`] of the channel when it is full.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
     pub fn send(&self, message: T) -> Result<(), EventSendError<T>> {
-        self.0.send(message).map_err(|err| EventSendError(err.0))
+        match self.policy {
+            BackpressurePolicy::Block => self.counted(message, |m| self.sender.send(m)),
+            BackpressurePolicy::DropNewest => {
+                match self.counted(message, |m| self.sender.try_send(m)) {
+                    Err(EventSendError::Full(_)) => Ok(()),
+                    result => result,
+                }
+            }
+            BackpressurePolicy::DropOldest => self.counted(message, |m| self.send_drop_oldest(m)),
+            BackpressurePolicy::Fail => match self.counted(message, |m| self.sender.try_send(m)) {
+                Err(EventSendError::Full(message)) => Err(EventSendError::Rejected(message)),
+                result => result,
+            },
+        }
+    }
+
+    /// Send a message if the channel has room for it, without blocking.
+    ///
+    /// The [`BackpressurePolicy`] of the channel is not applied: when the channel is full,
+    /// the message is handed back as an [`EventSendError::Full`] error.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    /// [`EventSendError::Full`]: crate::EventSendError::Full
+    pub fn try_send(&self, message: T) -> Result<(), EventSendError<T>> {
+        self.counted(message, |m| self.sender.try_send(m))
+    }
+
+    /// Send a message, waiting at most `timeout` for the channel to have room for it.
+    ///
+    /// The [`BackpressurePolicy`] of the channel is not applied: when the channel is still full
+    /// after `timeout`, the message is handed back as an [`EventSendError::Timeout`] error.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    /// [`EventSendError::Timeout`]: crate::EventSendError::Timeout
+    pub fn send_timeout(&self, message: T, timeout: Duration) -> Result<(), EventSendError<T>> {
+        self.counted(message, |m| self.sender.send_timeout(m, timeout))
+    }
+
+    /// Count a message as queued once it has been sent.
+    ///
+    /// A sender which is waiting for room in a full channel is not counted, so the number of
+    /// queued events does not exceed the capacity of the channel.
+    fn counted<F>(&self, message: T, send: F) -> Result<(), EventSendError<T>>
+    where
+        F: FnOnce(Message<T>) -> Result<(), EventSendError<Message<T>>>,
+    {
+        send(Message::Event(message)).map_err(|err| err.map(Message::into_event))?;
+        self.depth.sent();
+
+        Ok(())
+    }
+
+    fn send_drop_oldest(&self, mut message: Message<T>) -> Result<(), EventSendError<Message<T>>> {
+        loop {
+            match self.sender.try_send(message) {
+                Err(EventSendError::Full(returned)) => {
+                    message = returned;
+
+                    if let Some(oldest) = &self.oldest {
+                        // If the listener took an event in the meantime, there is nothing to
+                        // discard, and we can just try again.
+                        match oldest.try_recv() {
+                            Some(Message::Event(_)) => self.depth.received(),
+                            // The listener may still be handling the event sent right before
+                            // this barrier, so it is up to the listener to acknowledge it.
+                            Some(Message::Flush(barrier)) => self.barriers.skip(barrier),
+                            None => {}
+                        }
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Create another sender for the same channel.
+    ///
+    /// Not public: the [`ChannelReporter`] keeps track of additional senders, to be able to
+    /// guarantee it has been disconnected.
+    ///
+    /// [`ChannelReporter`]: crate::ChannelReporter
+    pub(crate) fn fork(&self) -> Self {
+        Self::new(
+            self.sender.clone(),
+            self.policy,
+            self.oldest.clone(),
+            self.depth.clone(),
+            self.barriers.clone(),
+        )
+    }
+
+    /// Block until the listener has taken every event sent before this call out of the channel.
+    ///
+    /// The barrier is sent regardless of the [`BackpressurePolicy`] of the channel, and waits
+    /// for room in the channel when it is full.
+    ///
+    /// Fails when the listener can no longer acknowledge the barrier, because its receivers have
+    /// been dropped, or its handler thread has exited.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    pub(crate) fn flush(&self) -> Result<(), FlushError> {
+        let barrier = Barrier::default();
+        let mut message = Message::Flush(barrier.clone());
+
+        // Waiting for room is interrupted now and then, to stop waiting once the listener is gone.
+        loop {
+            match self.sender.send_timeout(message, FLUSH_POLL_INTERVAL) {
+                Ok(()) => break,
+                Err(EventSendError::Timeout(returned)) if !self.barriers.is_closed() => {
+                    message = returned;
+                }
+                Err(_) => return Err(FlushError),
+            }
+        }
+
+        self.barriers.wait(&barrier)
     }
 
     /// When all senders are disconnected, the channel is disconnected
     pub fn disconnect(self) {
-        drop(self.0)
+        drop(self.sender)
     }
 }
 
 /// A receiver, used by `ChannelReporter` and `ChannelEventListener`.
-pub struct EventReceiver<T>(crossbeam_channel::Receiver<T>);
+pub struct EventReceiver<T> {
+    receiver: backend::Receiver<Message<T>>,
+    depth: Arc<QueueDepth>,
+    barriers: Arc<Barriers>,
+}
 
 impl<T> EventReceiver<T> {
+    fn new(
+        receiver: backend::Receiver<Message<T>>,
+        depth: Arc<QueueDepth>,
+        barriers: Arc<Barriers>,
+    ) -> Self {
+        barriers.add_receiver();
+
+        Self {
+            receiver,
+            depth,
+            barriers,
+        }
+    }
+
+    /// Receive the next event, blocking until one is available.
+    ///
+    /// Flush barriers are acknowledged as they are received: the caller is expected to
+    /// have handled all previously received events before receiving the next one.
     pub fn recv(&self) -> Result<T, EventRecvError> {
-        self.0.recv().map_err(|_| EventRecvError)
+        // The previously received event has been handled, so the barriers which a sender took
+        // out of the channel in the meantime, can be acknowledged as well.
+        self.barriers.ack_skipped();
+
+        loop {
+            match self.receiver.recv()? {
+                Message::Event(event) => {
+                    self.depth.received();
+                    return Ok(event);
+                }
+                Message::Flush(barrier) => self.barriers.ack(&barrier),
+            }
+        }
+    }
+
+    /// The number of events which have been sent, but not yet received.
+    pub fn len(&self) -> usize {
+        self.depth.current()
+    }
+
+    /// Whether all events which have been sent, have also been received.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The counters behind [`EventReceiver::len`], which outlive the receiver.
+    ///
+    /// [`EventReceiver::len`]: crate::EventReceiver::len
+    pub(crate) fn depth(&self) -> Arc<QueueDepth> {
+        self.depth.clone()
+    }
+
+    /// The flush barriers of the channel, which the handler thread closes when it exits.
+    pub(crate) fn barriers(&self) -> Arc<Barriers> {
+        self.barriers.clone()
     }
 }
 
 impl<T> Clone for EventReceiver<T> {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self::new(
+            self.receiver.clone(),
+            self.depth.clone(),
+            self.barriers.clone(),
+        )
     }
 }
 
+impl<T> Drop for EventReceiver<T> {
+    fn drop(&mut self) {
+        self.barriers.remove_receiver();
+    }
+}
+
+/// Keeps track of the number of events in a channel.
+pub(crate) struct QueueDepth {
+    /// The number of events which have been sent, but not yet received.
+    ///
+    /// Events are counted once they have been sent, so the receiver may take an event out of
+    /// the channel before it has been counted, which briefly makes this number negative.
+    current: AtomicIsize,
+    /// The highest number of events which have been queued at once.
+    peak: AtomicUsize,
+    /// The capacity of a bounded channel, which never holds more events than that.
+    capacity: Option<usize>,
+}
+
+impl QueueDepth {
+    fn new(capacity: Option<usize>) -> Self {
+        Self {
+            current: AtomicIsize::new(0),
+            peak: AtomicUsize::new(0),
+            capacity,
+        }
+    }
+
+    pub(crate) fn current(&self) -> usize {
+        self.clamp(self.current.load(Ordering::SeqCst))
+    }
+
+    pub(crate) fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+
+    fn sent(&self) {
+        let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(self.clamp(current), Ordering::SeqCst);
+    }
+
+    fn received(&self) {
+        self.current.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    // The counter can be off by the events which are being sent or received right now. The
+    // channel itself never holds a negative number of events, or more than its capacity.
+    fn clamp(&self, current: isize) -> usize {
+        let current = usize::try_from(current).unwrap_or(0);
+
+        match self.capacity {
+            Some(capacity) => current.min(capacity),
+            None => current,
+        }
+    }
+}
+
+/// How long [`EventSender::flush`] waits for room in a full channel, before checking whether
+/// the listener is still there.
+const FLUSH_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A flush barrier, which is acknowledged by setting its flag.
+#[derive(Clone, Default)]
+struct Barrier(Arc<AtomicBool>);
+
+/// Keeps track of the flush barriers of a channel, and of whether the listener is still there
+/// to acknowledge them.
+#[derive(Default)]
+pub(crate) struct Barriers {
+    state: Mutex<BarrierState>,
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct BarrierState {
+    /// Barriers which a sender took out of the channel, to make room for an event. The listener
+    /// may still be handling the event sent before such a barrier, so they are acknowledged when
+    /// the listener asks for its next event.
+    skipped: Vec<Barrier>,
+    /// The number of receivers of the listener.
+    receivers: usize,
+    /// Whether the listener stopped acknowledging barriers, because all of its receivers have
+    /// been dropped, or its handler thread has exited.
+    closed: bool,
+}
+
+impl Barriers {
+    fn lock(&self) -> MutexGuard<'_, BarrierState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn ack(&self, barrier: &Barrier) {
+        let _state = self.lock();
+        barrier.0.store(true, Ordering::SeqCst);
+        self.changed.notify_all();
+    }
+
+    fn skip(&self, barrier: Barrier) {
+        self.lock().skipped.push(barrier);
+    }
+
+    fn ack_skipped(&self) {
+        let mut state = self.lock();
+
+        if !state.skipped.is_empty() {
+            for barrier in state.skipped.drain(..) {
+                barrier.0.store(true, Ordering::SeqCst);
+            }
+
+            self.changed.notify_all();
+        }
+    }
+
+    fn add_receiver(&self) {
+        self.lock().receivers += 1;
+    }
+
+    fn remove_receiver(&self) {
+        let mut state = self.lock();
+        state.receivers -= 1;
+
+        if state.receivers == 0 {
+            state.closed = true;
+            self.changed.notify_all();
+        }
+    }
+
+    /// Stop waiting for barriers to be acknowledged, because the handler thread has exited.
+    pub(crate) fn close(&self) {
+        self.lock().closed = true;
+        self.changed.notify_all();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// Block until the barrier has been acknowledged, or until the listener is gone.
+    fn wait(&self, barrier: &Barrier) -> Result<(), FlushError> {
+        let mut state = self.lock();
+
+        loop {
+            if barrier.0.load(Ordering::SeqCst) {
+                return Ok(());
+            }
+
+            if state.closed {
+                return Err(FlushError);
+            }
+
+            state = self
+                .changed
+                .wait(state)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+    }
+}
+
+/// The reason why a message could not be sent. The message is handed back.
 #[derive(PartialEq, Eq, Clone, Copy)]
-pub struct EventSendError<T>(pub T);
+pub enum EventSendError<T> {
+    /// The channel has been disconnected, the message can no longer be received.
+    Disconnected(T),
+    /// The channel was full, and its [`BackpressurePolicy`] refused the message.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    Rejected(T),
+    /// The channel was full. Returned by [`EventSender::try_send`].
+    ///
+    /// [`EventSender::try_send`]: crate::EventSender::try_send
+    Full(T),
+    /// The channel was still full when the timeout elapsed. Returned by [`EventSender::send_timeout`].
+    ///
+    /// [`EventSender::send_timeout`]: crate::EventSender::send_timeout
+    Timeout(T),
+}
+
+impl<T> EventSendError<T> {
+    /// Take back the message which could not be sent.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Disconnected(message)
+            | Self::Rejected(message)
+            | Self::Full(message)
+            | Self::Timeout(message) => message,
+        }
+    }
+
+    pub(crate) fn map<U, F: FnOnce(T) -> U>(self, f: F) -> EventSendError<U> {
+        match self {
+            Self::Disconnected(message) => EventSendError::Disconnected(f(message)),
+            Self::Rejected(message) => EventSendError::Rejected(f(message)),
+            Self::Full(message) => EventSendError::Full(f(message)),
+            Self::Timeout(message) => EventSendError::Timeout(f(message)),
+        }
+    }
+}
 
 impl<T> fmt::Debug for EventSendError<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_fmt(format_args!("EventSendError({})", any::type_name::<T>()))
+        let variant = match self {
+            Self::Disconnected(_) => "Disconnected",
+            Self::Rejected(_) => "Rejected",
+            Self::Full(_) => "Full",
+            Self::Timeout(_) => "Timeout",
+        };
+
+        f.write_fmt(format_args!(
+            "EventSendError::{}({})",
+            variant,
+            any::type_name::<T>()
+        ))
     }
 }
 
@@ -1,10 +1,19 @@
-use crate::channel_reporter::reporter::{ChannelReporter, DisconnectToken, EventReporterError};
+use crate::channel_reporter::channel::{Barriers, QueueDepth};
+use crate::channel_reporter::reporter::ChannelReporter;
+use crate::channel_reporter::stats::HandlerStats;
 use crate::listener::HandlerGuard;
 use crate::reporter::EventReporter;
-use crate::{EventHandler, EventListener, EventReceiver};
-use std::sync::Arc;
-use std::thread;
+use crate::{
+    DisconnectToken, ErrorPolicy, EventHandler, EventListener, EventReceiver, FallibleEventHandler,
+    HandlerPanic,
+};
+use std::convert::Infallible;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use std::{error, thread};
 
 /// A listener which uses a channel to receive messages of type `Event`, and uses
 /// a thread to run the event handler (in [`ChannelEventListener::run_handler`]).
@@ -34,6 +43,87 @@ impl<Event> ChannelEventListener<Event> {
     }
 }
 
+impl<Event> ChannelEventListener<Event>
+where
+    Event: Send + 'static,
+{
+    /// Run a handler which can fail, in a separate thread.
+    ///
+    /// The `policy` decides whether the handler keeps handling events after an error.
+    /// The errors are returned when the returned guard is joined, as a [`JoinError::Handler`].
+    ///
+    /// [`JoinError::Handler`]: crate::JoinError::Handler
+    pub fn run_fallible_handler<H>(
+        &self,
+        handler: Arc<H>,
+        policy: ErrorPolicy,
+    ) -> ChannelHandlerGuard<H::Error>
+    where
+        H: FallibleEventHandler<Event = Event> + 'static,
+        H::Error: Send + 'static,
+    {
+        let event_receiver = self.event_receiver.clone();
+        let events_handled = Arc::new(AtomicUsize::new(0));
+        let counter = events_handled.clone();
+        let depth = self.event_receiver.depth();
+        let queue_depth = depth.clone();
+        let finished = Arc::new(Finished::default());
+        let signal = finished.clone();
+        let barriers = self.event_receiver.barriers();
+
+        let handle = thread::spawn(move || {
+            // Signals the guard, and pending flushes, when the thread exits, also when the
+            // handler panics.
+            let _signal = SignalOnExit(signal, barriers);
+            let started = Instant::now();
+            let mut stats = HandlerStats::default();
+            let mut errors = Vec::new();
+
+            'evl: loop {
+                match event_receiver.recv() {
+                    Ok(_) if policy == ErrorPolicy::StopOnFirstError && !errors.is_empty() => {}
+                    Ok(message) => {
+                        let start = Instant::now();
+                        let result = handler.handle(message);
+                        stats.record_handle(start.elapsed());
+
+                        if let Err(err) = result {
+                            errors.push(err);
+                        }
+
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    }
+                    Err(_disconnect) => {
+                        if policy == ErrorPolicy::Collect || errors.is_empty() {
+                            let start = Instant::now();
+                            let result = handler.finish();
+                            stats.finish_time = start.elapsed();
+
+                            if let Err(err) = result {
+                                errors.push(err);
+                            }
+                        }
+
+                        break 'evl;
+                    }
+                }
+            }
+
+            stats.peak_queue_depth = queue_depth.peak();
+            stats.wall_time = started.elapsed();
+
+            (errors, stats)
+        });
+
+        ChannelHandlerGuard {
+            handle: Some(handle),
+            events_handled,
+            depth,
+            finished,
+        }
+    }
+}
+
 impl<Event> EventListener for ChannelEventListener<Event>
 where
     Event: Send + 'static,
@@ -45,19 +135,25 @@ where
     where
         H: EventHandler<Event = Self::Event> + 'static,
     {
-        let event_receiver = self.event_receiver.clone();
+        self.run_fallible_handler(Arc::new(NeverFails(handler)), ErrorPolicy::Collect)
+    }
+}
 
-        let handle = thread::spawn(move || 'evl: loop {
-            match event_receiver.recv() {
-                Ok(message) => handler.handle(message),
-                Err(_disconnect) => {
-                    handler.finish();
-                    break 'evl;
-                }
-            }
-        });
+/// Runs an [`EventHandler`] as a [`FallibleEventHandler`] which never fails.
+struct NeverFails<H>(Arc<H>);
 
-        ChannelHandlerGuard::new(handle)
+impl<H: EventHandler> FallibleEventHandler for NeverFails<H> {
+    type Event = H::Event;
+    type Error = Infallible;
+
+    fn handle(&self, event: Self::Event) -> Result<(), Self::Error> {
+        self.0.handle(event);
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), Self::Error> {
+        self.0.finish();
+        Ok(())
     }
 }
 
@@ -65,6 +161,9 @@ where
 ///
 /// Holds the handler thread and allows waiting for it to finish via [`HandlerGuard::join`].
 ///
+/// The type parameter `E` is the error type of a [`FallibleEventHandler`]. For an
+/// [`EventHandler`], which can not fail, it is [`Infallible`].
+///
 /// ### Ordering
 ///
 /// [`HandlerGuard::join`] requires a [`DisconnectToken`] proof token produced by
@@ -75,27 +174,27 @@ where
 /// ### Drop behaviour
 ///
 /// Dropping this guard without calling [`join`] is a programming error and will panic
-/// (unless the thread is already unwinding).
+/// (unless the thread is already unwinding). To stop waiting for a handler thread which does not
+/// finish, use [`ChannelHandlerGuard::abandon`] instead.
 ///
 /// [`HandlerGuard`]: crate::HandlerGuard
 /// [`HandlerGuard::join`]: crate::HandlerGuard::join
+/// [`FallibleEventHandler`]: crate::FallibleEventHandler
+/// [`EventHandler`]: crate::EventHandler
+/// [`Infallible`]: std::convert::Infallible
 /// [`ChannelEventListener`]: crate::ChannelEventListener
 /// [`ChannelReporter::disconnect`]: crate::ChannelReporter::disconnect
 /// [`join`]: HandlerGuard::join
+/// [`ChannelHandlerGuard::abandon`]: crate::ChannelHandlerGuard::abandon
 #[must_use]
-pub struct ChannelHandlerGuard {
-    handle: Option<JoinHandle<()>>,
-}
-
-impl ChannelHandlerGuard {
-    fn new(handle: JoinHandle<()>) -> Self {
-        Self {
-            handle: Some(handle),
-        }
-    }
+pub struct ChannelHandlerGuard<E = Infallible> {
+    handle: Option<JoinHandle<(Vec<E>, HandlerStats)>>,
+    events_handled: Arc<AtomicUsize>,
+    depth: Arc<QueueDepth>,
+    finished: Arc<Finished>,
 }
 
-impl ChannelHandlerGuard {
+impl<E> ChannelHandlerGuard<E> {
     /// Disconnect the reporter and join the handler guard in one step.
     ///
     /// This is a shorthand for:
@@ -105,27 +204,120 @@ impl ChannelHandlerGuard {
     /// guard.join(token)?;
     /// ```
     ///
-    /// If the handler thread panicked, the panic is re-raised in the calling thread.
+    /// Disconnecting a [`ChannelReporter`] does not fail, so only the errors of joining the
+    /// handler are returned.
+    ///
+    /// [`ChannelReporter`]: crate::ChannelReporter
     pub fn disconnect_and_join<Event: Send>(
         self,
         reporter: ChannelReporter<Event>,
-    ) -> Result<(), EventReporterError<Event>> {
-        let token = reporter.disconnect()?;
-        self.join(token).expect("handler thread panicked");
-        Ok(())
+    ) -> Result<(), JoinError<E>> {
+        let token = match reporter.disconnect() {
+            Ok(token) => token,
+            Err(_) => unreachable!("disconnecting a ChannelReporter does not fail"),
+        };
+
+        self.join(token)
+    }
+
+    /// Wait for the handler thread to finish, like [`HandlerGuard::join`], and return
+    /// statistics about the work of the handler.
+    ///
+    /// When the handler returned errors, the statistics are returned together with the errors,
+    /// in a [`JoinWithStatsError`]. When the handler panicked, there are no statistics.
+    ///
+    /// [`HandlerGuard::join`]: crate::HandlerGuard::join
+    /// [`JoinWithStatsError`]: crate::JoinWithStatsError
+    pub fn join_with_stats(
+        mut self,
+        _token: DisconnectToken,
+    ) -> Result<HandlerStats, JoinWithStatsError<E>> {
+        match self.handle.take().unwrap().join() {
+            Ok((errors, stats)) if errors.is_empty() => Ok(stats),
+            Ok((errors, stats)) => Err(JoinWithStatsError {
+                error: JoinError::Handler(errors),
+                stats: Some(stats),
+            }),
+            Err(payload) => Err(JoinWithStatsError {
+                error: JoinError::Panicked(HandlerPanic::new(
+                    payload,
+                    self.events_handled.load(Ordering::SeqCst),
+                )),
+                stats: None,
+            }),
+        }
+    }
+
+    /// Join the handler thread, waiting at most `timeout` for it to finish.
+    ///
+    /// See [`ChannelHandlerGuard::join_deadline`].
+    ///
+    /// [`ChannelHandlerGuard::join_deadline`]: crate::ChannelHandlerGuard::join_deadline
+    pub fn join_timeout(
+        self,
+        token: DisconnectToken,
+        timeout: Duration,
+    ) -> Result<(), JoinTimeoutError<E>> {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => self.join_deadline(token, deadline),
+            None => self.join(token).map_err(JoinTimeoutError::Failed),
+        }
+    }
+
+    /// Join the handler thread, if it finishes before the `deadline`.
+    ///
+    /// When the handler is still busy at the deadline, the guard and the token are handed back
+    /// as a [`JoinTimeoutError::TimedOut`] error. They can be used to wait some more, to report
+    /// how many events were still queued (see [`ChannelHandlerGuard::events_queued`]), or to
+    /// give up on the handler thread with [`ChannelHandlerGuard::abandon`].
+    ///
+    /// [`JoinTimeoutError::TimedOut`]: crate::JoinTimeoutError::TimedOut
+    /// [`ChannelHandlerGuard::events_queued`]: crate::ChannelHandlerGuard::events_queued
+    /// [`ChannelHandlerGuard::abandon`]: crate::ChannelHandlerGuard::abandon
+    pub fn join_deadline(
+        self,
+        token: DisconnectToken,
+        deadline: Instant,
+    ) -> Result<(), JoinTimeoutError<E>> {
+        if self.finished.wait_until(deadline) {
+            self.join(token).map_err(JoinTimeoutError::Failed)
+        } else {
+            Err(JoinTimeoutError::TimedOut { guard: self, token })
+        }
+    }
+
+    /// The number of events which have been reported, but which the handler has not yet
+    /// received. Events which are still waiting for room in a full channel are not counted.
+    pub fn events_queued(&self) -> usize {
+        self.depth.current()
+    }
+
+    /// The number of events the handler finished handling so far.
+    pub fn events_handled(&self) -> usize {
+        self.events_handled.load(Ordering::SeqCst)
+    }
+
+    /// Stop waiting for the handler thread, without joining it.
+    ///
+    /// The thread is detached: it keeps running in the background until it finishes, or
+    /// until the process exits. Its outcome, including errors and panics, is discarded.
+    pub fn abandon(mut self) {
+        drop(self.handle.take());
     }
 }
 
-impl HandlerGuard for ChannelHandlerGuard {
-    type Err = ();
+impl<E> HandlerGuard for ChannelHandlerGuard<E> {
+    type Err = JoinError<E>;
     type Token = DisconnectToken;
 
-    fn join(mut self, _token: DisconnectToken) -> Result<(), Self::Err> {
-        self.handle.take().unwrap().join().map_err(|_| ())
+    fn join(self, token: DisconnectToken) -> Result<(), Self::Err> {
+        self.join_with_stats(token)
+            .map(|_| ())
+            .map_err(JoinWithStatsError::into_error)
     }
 }
 
-impl Drop for ChannelHandlerGuard {
+impl<E> Drop for ChannelHandlerGuard<E> {
     fn drop(&mut self) {
         if self.handle.is_some() && !thread::panicking() {
             panic!(
@@ -135,3 +327,156 @@ impl Drop for ChannelHandlerGuard {
         }
     }
 }
+
+/// Signals whether the handler thread has exited.
+///
+/// A [`JoinHandle`] can't be joined with a timeout, so the guard waits for this signal instead.
+#[derive(Default)]
+struct Finished {
+    done: Mutex<bool>,
+    signal: Condvar,
+}
+
+impl Finished {
+    fn set(&self) {
+        *self.done.lock().unwrap_or_else(|err| err.into_inner()) = true;
+        self.signal.notify_all();
+    }
+
+    /// Wait until the thread has exited, or the deadline has passed. Returns whether the
+    /// thread has exited.
+    fn wait_until(&self, deadline: Instant) -> bool {
+        let mut done = self.done.lock().unwrap_or_else(|err| err.into_inner());
+
+        while !*done {
+            let now = Instant::now();
+
+            if now >= deadline {
+                return false;
+            }
+
+            done = self
+                .signal
+                .wait_timeout(done, deadline - now)
+                .unwrap_or_else(|err| err.into_inner())
+                .0;
+        }
+
+        true
+    }
+}
+
+/// Sets the [`Finished`] signal, and closes the flush [`Barriers`] of the channel, when dropped,
+/// which also happens when the handler unwinds.
+struct SignalOnExit(Arc<Finished>, Arc<Barriers>);
+
+impl Drop for SignalOnExit {
+    fn drop(&mut self) {
+        self.0.set();
+        self.1.close();
+    }
+}
+
+/// The reason why joining a [`ChannelHandlerGuard`] failed.
+///
+/// [`ChannelHandlerGuard`]: crate::ChannelHandlerGuard
+#[derive(Debug, PartialEq, Eq)]
+pub enum JoinError<E = Infallible> {
+    /// The handler thread panicked.
+    Panicked(HandlerPanic),
+    /// The handler returned one or more errors, in the order in which they occurred.
+    ///
+    /// With [`ErrorPolicy::StopOnFirstError`], this is a single error.
+    ///
+    /// [`ErrorPolicy::StopOnFirstError`]: crate::ErrorPolicy::StopOnFirstError
+    Handler(Vec<E>),
+}
+
+impl<E> Display for JoinError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panicked(panic) => Display::fmt(panic, f),
+            Self::Handler(errors) => f.write_fmt(format_args!(
+                "handler failed with {} error(s)",
+                errors.len()
+            )),
+        }
+    }
+}
+
+impl<E: Debug> error::Error for JoinError<E> {}
+
+/// The reason why [`ChannelHandlerGuard::join_with_stats`] failed, together with the statistics
+/// of the handler, if it did not panic.
+///
+/// [`ChannelHandlerGuard::join_with_stats`]: crate::ChannelHandlerGuard::join_with_stats
+#[derive(Debug, PartialEq, Eq)]
+pub struct JoinWithStatsError<E = Infallible> {
+    error: JoinError<E>,
+    stats: Option<HandlerStats>,
+}
+
+impl<E> JoinWithStatsError<E> {
+    /// The reason why joining failed.
+    pub fn error(&self) -> &JoinError<E> {
+        &self.error
+    }
+
+    /// The statistics of the handler, unless it panicked.
+    pub fn stats(&self) -> Option<&HandlerStats> {
+        self.stats.as_ref()
+    }
+
+    /// Take the reason why joining failed, discarding the statistics.
+    pub fn into_error(self) -> JoinError<E> {
+        self.error
+    }
+}
+
+impl<E> Display for JoinWithStatsError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: Debug> error::Error for JoinWithStatsError<E> {}
+
+/// The reason why joining a [`ChannelHandlerGuard`] with a timeout or deadline failed.
+///
+/// [`ChannelHandlerGuard`]: crate::ChannelHandlerGuard
+pub enum JoinTimeoutError<E = Infallible> {
+    /// The handler did not finish in time. The guard and token are handed back, so joining
+    /// can be retried, or the handler thread can be abandoned.
+    TimedOut {
+        guard: ChannelHandlerGuard<E>,
+        token: DisconnectToken,
+    },
+    /// The handler finished in time, but joining it failed.
+    Failed(JoinError<E>),
+}
+
+impl<E: Debug> Debug for JoinTimeoutError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut { guard, .. } => f
+                .debug_struct("TimedOut")
+                .field("events_queued", &guard.events_queued())
+                .finish_non_exhaustive(),
+            Self::Failed(err) => f.debug_tuple("Failed").field(err).finish(),
+        }
+    }
+}
+
+impl<E> Display for JoinTimeoutError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut { guard, .. } => f.write_fmt(format_args!(
+                "handler did not finish in time, with {} event(s) still queued",
+                guard.events_queued()
+            )),
+            Self::Failed(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: Debug> error::Error for JoinTimeoutError<E> {}
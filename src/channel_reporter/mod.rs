@@ -1,3 +1,6 @@
+mod backend;
 pub mod channel;
 pub mod listener;
 pub mod reporter;
+pub mod scope;
+pub mod stats;
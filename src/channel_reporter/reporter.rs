@@ -1,16 +1,8 @@
-use crate::{EventReporter, EventSender};
+use crate::{DisconnectToken, EventReporter, EventSendError, EventSender};
 use std::error;
 use std::fmt::{Debug, Display, Formatter};
-
-/// Proof that a [`ChannelReporter`] has been disconnected.
-///
-/// Produced by [`ChannelReporter::disconnect`] and consumed by
-/// [`ChannelHandlerGuard::join`]. Because the inner field is private
-/// and this type has no public constructor, the only way to obtain a `DisconnectToken` value
-/// is by calling [`ChannelReporter::disconnect`], which should guarantee correct ordering.
-///
-/// [`ChannelHandlerGuard::join`]: crate::ChannelHandlerGuard::join
-pub struct DisconnectToken(());
+use std::marker::PhantomData;
+use std::time::Duration;
 
 /// A specialized type of reporter which uses a channel to transmit messages.
 ///
@@ -24,11 +16,15 @@ pub struct DisconnectToken(());
 ///
 /// The [`EventListener`] associated with this reporter is the [`ChannelEventListener`].
 ///
+/// Additional reporters for the same channel, for example for other threads, can be created
+/// with [`ChannelReporter::fork`].
+///
 /// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
 /// [`HandlerGuard::join`]: crate::HandlerGuard::join
 /// [`event_channel()`]: crate::event_channel()
 /// [`EventListener`]: crate::EventListener
 /// [`ChannelEventListener`]: crate::ChannelEventListener
+/// [`ChannelReporter::fork`]: crate::ChannelReporter::fork
 pub struct ChannelReporter<Event> {
     event_sender: EventSender<Event>,
 }
@@ -41,6 +37,115 @@ impl<Event> ChannelReporter<Event> {
     pub fn new(event_sender: EventSender<Event>) -> Self {
         Self { event_sender }
     }
+
+    /// Create an additional reporter, which sends events over the same channel.
+    ///
+    /// Unlike the `ChannelReporter` itself, a [`ReporterHandle`] can be cloned and be sent to
+    /// other threads.
+    ///
+    /// A handle borrows the `ChannelReporter`, so it can't outlive it: by the time the
+    /// `ChannelReporter` is disconnected, every handle is gone, and the channel is disconnected.
+    /// To report from other threads, use scoped threads, for example with `std::thread::scope`:
+    ///
+    /// ```
+    /// use storyteller::{event_channel, ChannelReporter, EventReporter};
+    ///
+    /// let (sender, receiver) = event_channel::<usize>();
+    /// let reporter = ChannelReporter::new(sender);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for i in 0..4usize {
+    ///         let handle = reporter.fork();
+    ///         s.spawn(move || handle.report_event(i).unwrap());
+    ///     }
+    /// });
+    ///
+    /// let _token = reporter.disconnect().unwrap();
+    /// assert_eq!(receiver.len(), 4);
+    /// ```
+    ///
+    /// A handle which is still in use, can't be kept around past the disconnect:
+    ///
+    /// ```compile_fail
+    /// use storyteller::{event_channel, ChannelReporter, EventReporter};
+    ///
+    /// let (sender, _receiver) = event_channel::<usize>();
+    /// let reporter = ChannelReporter::new(sender);
+    /// let handle = reporter.fork();
+    ///
+    /// let _token = reporter.disconnect().unwrap();
+    /// handle.report_event(1).unwrap();
+    /// ```
+    ///
+    /// ### Limits
+    ///
+    /// * Because a handle borrows the reporter, it can't be moved into a thread or task which
+    ///   requires `'static`, such as a thread spawned with `std::thread::spawn`, or a worker pool.
+    /// * The borrow only ends when the handle is dropped, or when it is leaked, for example with
+    ///   `std::mem::forget`. A leaked handle keeps the channel connected, so the handler keeps
+    ///   waiting for events, and joining it after disconnecting the reporter blocks forever.
+    ///   Don't leak handles.
+    ///
+    /// [`ReporterHandle`]: crate::ReporterHandle
+    pub fn fork(&self) -> ReporterHandle<'_, Event> {
+        ReporterHandle {
+            event_sender: self.event_sender.fork(),
+            _reporter: PhantomData,
+        }
+    }
+
+    /// Send an event to the listener, if the channel has room for it, without blocking.
+    ///
+    /// When the channel is full, the event is handed back as an [`EventReporterError::Full`]
+    /// error, regardless of the [`BackpressurePolicy`] of the channel.
+    ///
+    /// [`EventReporterError::Full`]: crate::EventReporterError::Full
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    pub fn try_report_event(
+        &self,
+        event: impl Into<Event>,
+    ) -> Result<(), EventReporterError<Event>> {
+        self.event_sender
+            .try_send(event.into())
+            .map_err(EventReporterError::from)
+    }
+
+    /// Send an event to the listener, waiting at most `timeout` for the channel to have room
+    /// for it.
+    ///
+    /// When the channel is still full after `timeout`, the event is handed back as an
+    /// [`EventReporterError::Timeout`] error, regardless of the [`BackpressurePolicy`] of
+    /// the channel.
+    ///
+    /// [`EventReporterError::Timeout`]: crate::EventReporterError::Timeout
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    pub fn report_event_timeout(
+        &self,
+        event: impl Into<Event>,
+        timeout: Duration,
+    ) -> Result<(), EventReporterError<Event>> {
+        self.event_sender
+            .send_timeout(event.into(), timeout)
+            .map_err(EventReporterError::from)
+    }
+
+    /// Block until the handler has handled every event which was reported before this call.
+    ///
+    /// Unlike [`EventReporter::disconnect`], the reporter can still be used afterwards, which
+    /// makes this useful as a synchronisation point, for example before prompting the user
+    /// while the handler writes to the terminal. Events reported concurrently, from a
+    /// [`ReporterHandle`], may be handled before this call returns as well.
+    ///
+    /// Returns a [`FlushError`] if the listener has been dropped, or if the handler thread has
+    /// exited, for example because it panicked. If no handler has been started for the
+    /// listener yet, this call blocks until one is started and handles the events.
+    ///
+    /// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+    /// [`ReporterHandle`]: crate::ReporterHandle
+    /// [`FlushError`]: crate::FlushError
+    pub fn flush(&self) -> Result<(), FlushError> {
+        self.event_sender.flush()
+    }
 }
 
 impl<Event> EventReporter for ChannelReporter<Event> {
@@ -48,10 +153,17 @@ impl<Event> EventReporter for ChannelReporter<Event> {
     type Err = EventReporterError<Event>;
     type DisconnectToken = DisconnectToken;
 
+    /// Send an event to the listener.
+    ///
+    /// If the channel was created with [`bounded_event_channel`] and is full, the
+    /// [`BackpressurePolicy`] of the channel decides what happens to the event.
+    ///
+    /// [`bounded_event_channel`]: crate::bounded_event_channel
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
     fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
         self.event_sender
             .send(event.into())
-            .map_err(EventReporterError::SendError)
+            .map_err(EventReporterError::from)
     }
 
     /// Disconnect the sender, returning a [`DisconnectToken`] token.
@@ -59,41 +171,205 @@ impl<Event> EventReporter for ChannelReporter<Event> {
     /// Pass the token to [`HandlerGuard::join`] to wait for the handler thread to finish
     /// draining the queue. The token enforces that this call happens first.
     ///
+    /// Handles created with [`ChannelReporter::fork`] borrow the reporter, so they have all
+    /// been dropped by now (unless one was leaked), and the channel is disconnected right away.
+    ///
     /// [`HandlerGuard::join`]: crate::HandlerGuard::join
+    /// [`ChannelReporter::fork`]: crate::ChannelReporter::fork
     fn disconnect(self) -> Result<DisconnectToken, Self::Err> {
         self.event_sender.disconnect();
-        Ok(DisconnectToken(()))
+        Ok(DisconnectToken::new())
     }
 }
 
+/// An additional reporter for the channel of a [`ChannelReporter`], created by
+/// [`ChannelReporter::fork`].
+///
+/// Handles can be cloned, and sent to other threads. A handle borrows the [`ChannelReporter`]
+/// it was forked from, so it can't outlive it: the [`ChannelReporter`] can only be
+/// disconnected once all of its handles are gone.
+///
+/// Disconnecting a handle does not produce a [`DisconnectToken`]: only the [`ChannelReporter`]
+/// issues one.
+///
+/// Because of the borrow, a handle can't be sent to a thread which requires `'static`. And a
+/// handle must not be leaked (e.g. with `std::mem::forget`): a leaked handle keeps the channel
+/// connected, so joining the handler blocks forever. See the limits of
+/// [`ChannelReporter::fork`].
+///
+/// [`ChannelReporter`]: crate::ChannelReporter
+/// [`ChannelReporter::fork`]: crate::ChannelReporter::fork
+/// [`DisconnectToken`]: crate::DisconnectToken
+pub struct ReporterHandle<'r, Event> {
+    event_sender: EventSender<Event>,
+    _reporter: PhantomData<&'r ()>,
+}
+
+impl<'r, Event> ReporterHandle<'r, Event> {
+    /// Send an event to the listener, if the channel has room for it, without blocking.
+    ///
+    /// See [`ChannelReporter::try_report_event`].
+    ///
+    /// [`ChannelReporter::try_report_event`]: crate::ChannelReporter::try_report_event
+    pub fn try_report_event(
+        &self,
+        event: impl Into<Event>,
+    ) -> Result<(), EventReporterError<Event>> {
+        self.event_sender
+            .try_send(event.into())
+            .map_err(EventReporterError::from)
+    }
+
+    /// Send an event to the listener, waiting at most `timeout` for the channel to have room
+    /// for it.
+    ///
+    /// See [`ChannelReporter::report_event_timeout`].
+    ///
+    /// [`ChannelReporter::report_event_timeout`]: crate::ChannelReporter::report_event_timeout
+    pub fn report_event_timeout(
+        &self,
+        event: impl Into<Event>,
+        timeout: Duration,
+    ) -> Result<(), EventReporterError<Event>> {
+        self.event_sender
+            .send_timeout(event.into(), timeout)
+            .map_err(EventReporterError::from)
+    }
+
+    /// Block until the handler has handled every event which was reported before this call.
+    ///
+    /// See [`ChannelReporter::flush`].
+    ///
+    /// [`ChannelReporter::flush`]: crate::ChannelReporter::flush
+    pub fn flush(&self) -> Result<(), FlushError> {
+        self.event_sender.flush()
+    }
+}
+
+impl<'r, Event> Clone for ReporterHandle<'r, Event> {
+    fn clone(&self) -> Self {
+        Self {
+            event_sender: self.event_sender.fork(),
+            _reporter: PhantomData,
+        }
+    }
+}
+
+impl<'r, Event> EventReporter for ReporterHandle<'r, Event> {
+    type Event = Event;
+    type Err = EventReporterError<Event>;
+    type DisconnectToken = ();
+
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        self.event_sender
+            .send(event.into())
+            .map_err(EventReporterError::from)
+    }
+
+    /// Disconnect this handle.
+    ///
+    /// The [`ChannelReporter`] this handle was forked from issues the [`DisconnectToken`].
+    ///
+    /// [`ChannelReporter`]: crate::ChannelReporter
+    /// [`DisconnectToken`]: crate::DisconnectToken
+    fn disconnect(self) -> Result<(), Self::Err> {
+        drop(self);
+        Ok(())
+    }
+}
+
+/// The reason why an event could not be reported. The event is handed back.
 pub enum EventReporterError<Event> {
-    SendError(crate::EventSendError<Event>),
+    /// The listener has been disconnected, so the event can no longer be received.
+    Disconnected(Event),
+    /// The channel was full, and its [`BackpressurePolicy`] refused the event.
+    ///
+    /// [`BackpressurePolicy`]: crate::BackpressurePolicy
+    Rejected(Event),
+    /// The channel was full. Returned by [`ChannelReporter::try_report_event`].
+    ///
+    /// [`ChannelReporter::try_report_event`]: crate::ChannelReporter::try_report_event
+    Full(Event),
+    /// The channel was still full when the timeout elapsed. Returned by
+    /// [`ChannelReporter::report_event_timeout`].
+    ///
+    /// [`ChannelReporter::report_event_timeout`]: crate::ChannelReporter::report_event_timeout
+    Timeout(Event),
 }
 
-impl<Event> Debug for EventReporterError<Event> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl<Event> EventReporterError<Event> {
+    /// Take back the event which could not be reported.
+    pub fn into_event(self) -> Event {
+        match self {
+            Self::Disconnected(event)
+            | Self::Rejected(event)
+            | Self::Full(event)
+            | Self::Timeout(event) => event,
+        }
+    }
+
+    fn variant(&self) -> &'static str {
         match self {
-            Self::SendError(_) => f.write_fmt(format_args!(
-                "SendError(EventSendError({}))",
-                std::any::type_name::<Event>()
-            )),
+            Self::Disconnected(_) => "Disconnected",
+            Self::Rejected(_) => "Rejected",
+            Self::Full(_) => "Full",
+            Self::Timeout(_) => "Timeout",
         }
     }
 }
 
+impl<Event> From<EventSendError<Event>> for EventReporterError<Event> {
+    fn from(err: EventSendError<Event>) -> Self {
+        match err {
+            EventSendError::Disconnected(event) => Self::Disconnected(event),
+            EventSendError::Rejected(event) => Self::Rejected(event),
+            EventSendError::Full(event) => Self::Full(event),
+            EventSendError::Timeout(event) => Self::Timeout(event),
+        }
+    }
+}
+
+impl<Event> Debug for EventReporterError<Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{}({})",
+            self.variant(),
+            std::any::type_name::<Event>()
+        ))
+    }
+}
+
 impl<Event> Display for EventReporterError<Event>
 where
     Event: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::SendError(crate::EventSendError(ev)) => f.write_fmt(format_args!(
-                "SendError(EventSendError({} = '{}'))",
-                std::any::type_name::<Event>(),
-                ev
-            )),
-        }
+        let ev = match self {
+            Self::Disconnected(ev) | Self::Rejected(ev) | Self::Full(ev) | Self::Timeout(ev) => ev,
+        };
+
+        f.write_fmt(format_args!(
+            "{}({} = '{}')",
+            self.variant(),
+            std::any::type_name::<Event>(),
+            ev
+        ))
     }
 }
 
 impl<Event> error::Error for EventReporterError<Event> where Event: Display {}
+
+/// The reason why a [`ChannelReporter`] could not be flushed: the listener has been dropped,
+/// so the reported events will not be handled.
+///
+/// [`ChannelReporter`]: crate::ChannelReporter
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FlushError;
+
+impl Display for FlushError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unable to flush: the listener has been disconnected")
+    }
+}
+
+impl error::Error for FlushError {}
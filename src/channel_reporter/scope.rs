@@ -0,0 +1,82 @@
+//! A scoped entry point, which runs a handler for the duration of a closure.
+
+use crate::{
+    event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener, JoinError,
+};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+/// Run the `handler` in a separate thread, for the duration of the closure `f`.
+///
+/// A [`ChannelReporter`] is passed to `f`, to report events to the `handler`. When `f` returns,
+/// the reporter is disconnected, and the handler thread is joined, so every reported event has
+/// been handled by the time this function returns. This takes care of the disconnect-then-join
+/// sequence, which would otherwise be done by hand with [`EventReporter::disconnect`] and
+/// [`HandlerGuard::join`].
+///
+/// The result of `f` is returned, together with the result of joining the handler thread.
+///
+/// If `f` panics, the handler thread is joined before the panic is resumed, so the events
+/// reported up to the panic are handled.
+///
+/// ```
+/// use std::sync::Arc;
+/// use storyteller::EventHandler;
+///
+/// struct PrintHandler;
+///
+/// impl EventHandler for PrintHandler {
+///     type Event = String;
+///
+///     fn handle(&self, event: Self::Event) {
+///         println!("{}", event);
+///     }
+/// }
+///
+/// let (value, joined) = storyteller::scope(Arc::new(PrintHandler), |reporter| {
+///     use storyteller::EventReporter;
+///
+///     reporter.report_event("computing...").unwrap();
+///     42
+/// });
+///
+/// assert_eq!(value, 42);
+/// assert!(joined.is_ok());
+/// ```
+///
+/// ### Handles
+///
+/// Handles created with [`ChannelReporter::fork`] borrow the reporter, so they can't escape `f`,
+/// and they are all gone by the time the reporter is disconnected:
+///
+/// ```compile_fail
+/// use std::sync::Arc;
+/// use storyteller::handler_fn;
+///
+/// let handler = Arc::new(handler_fn(|_event: usize| {}));
+/// let (handle, _joined) = storyteller::scope(handler, |reporter| reporter.fork());
+/// ```
+///
+/// [`ChannelReporter`]: crate::ChannelReporter
+/// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+/// [`ChannelReporter::fork`]: crate::ChannelReporter::fork
+/// [`HandlerGuard::join`]: crate::HandlerGuard::join
+pub fn scope<H, F, R>(handler: Arc<H>, f: F) -> (R, Result<(), JoinError>)
+where
+    H: EventHandler + 'static,
+    H::Event: Send + 'static,
+    F: FnOnce(&ChannelReporter<H::Event>) -> R,
+{
+    let (sender, receiver) = event_channel();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(handler);
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| f(&reporter)));
+    let joined = guard.disconnect_and_join(reporter);
+
+    match result {
+        Ok(value) => (value, joined),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
@@ -0,0 +1,54 @@
+//! Statistics about a handler, collected by the `ChannelEventListener`.
+
+use std::time::Duration;
+
+/// A summary of the work of a handler, returned by [`ChannelHandlerGuard::join_with_stats`].
+///
+/// [`ChannelHandlerGuard::join_with_stats`]: crate::ChannelHandlerGuard::join_with_stats
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct HandlerStats {
+    pub(crate) events_handled: usize,
+    pub(crate) handle_time: Duration,
+    pub(crate) max_handle_time: Duration,
+    pub(crate) finish_time: Duration,
+    pub(crate) peak_queue_depth: usize,
+    pub(crate) wall_time: Duration,
+}
+
+impl HandlerStats {
+    /// The number of events the handler handled.
+    pub fn events_handled(&self) -> usize {
+        self.events_handled
+    }
+
+    /// The total time spent handling events.
+    pub fn handle_time(&self) -> Duration {
+        self.handle_time
+    }
+
+    /// The longest time spent handling a single event.
+    pub fn max_handle_time(&self) -> Duration {
+        self.max_handle_time
+    }
+
+    /// The time spent finishing the handler, after the reporter disconnected.
+    pub fn finish_time(&self) -> Duration {
+        self.finish_time
+    }
+
+    /// The highest number of events which were queued at once, waiting to be handled.
+    pub fn peak_queue_depth(&self) -> usize {
+        self.peak_queue_depth
+    }
+
+    /// The time between starting the handler thread and the handler finishing.
+    pub fn wall_time(&self) -> Duration {
+        self.wall_time
+    }
+
+    pub(crate) fn record_handle(&mut self, elapsed: Duration) {
+        self.events_handled += 1;
+        self.handle_time += elapsed;
+        self.max_handle_time = self.max_handle_time.max(elapsed);
+    }
+}
@@ -0,0 +1,135 @@
+//! Reporters which attach context to every event they report.
+
+use crate::EventReporter;
+
+/// An event which can take in a context value, for example the name of the workspace member
+/// or the target for which the event is reported.
+///
+/// Implement this trait for an event type to merge the context into the event itself, or use
+/// [`WithContext`] to wrap events together with their context.
+///
+/// [`WithContext`]: crate::WithContext
+pub trait MergeContext<C> {
+    /// Merge the `context` into this event.
+    ///
+    /// With nested [`ContextReporter`]s, the context of the innermost reporter is merged first,
+    /// and the context of the outermost reporter last.
+    ///
+    /// [`ContextReporter`]: crate::ContextReporter
+    fn merge_context(&mut self, context: &C);
+}
+
+/// A reporter which merges a context value into every event, before passing it on to the
+/// reporter it was created from.
+///
+/// Created by [`EventReporterExt::with_context`]. It borrows the reporter it was created from,
+/// so it is cheap to create one for every task. Context reporters can be nested, in which case
+/// the context of each of them is merged into the event.
+///
+/// ```
+/// # #[cfg(feature = "std_channel")]
+/// # {
+/// use storyteller::{event_channel, ChannelReporter, EventReporter, EventReporterExt, WithContext};
+///
+/// let (sender, receiver) = event_channel::<WithContext<&str, &str>>();
+/// let reporter = ChannelReporter::new(sender);
+///
+/// let member = reporter.with_context("member-a");
+/// let target = member.with_context("x86_64-unknown-linux-gnu");
+///
+/// target.report_event("compiling").unwrap();
+///
+/// let event = receiver.recv().unwrap();
+/// assert_eq!(event.event(), &"compiling");
+/// assert_eq!(event.contexts(), &["member-a", "x86_64-unknown-linux-gnu"]);
+/// # }
+/// ```
+///
+/// Disconnecting a `ContextReporter` does nothing: the reporter it was created from needs to
+/// be disconnected.
+///
+/// [`EventReporterExt::with_context`]: crate::EventReporterExt::with_context
+pub struct ContextReporter<'reporter, R, C> {
+    reporter: &'reporter R,
+    context: C,
+}
+
+impl<'reporter, R, C> ContextReporter<'reporter, R, C> {
+    pub(crate) fn new(reporter: &'reporter R, context: C) -> Self {
+        Self { reporter, context }
+    }
+
+    /// The context which is merged into every event.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+}
+
+impl<'reporter, R, C> EventReporter for ContextReporter<'reporter, R, C>
+where
+    R: EventReporter,
+    R::Event: MergeContext<C>,
+{
+    type Event = R::Event;
+    type Err = R::Err;
+    type DisconnectToken = ();
+
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        let mut event = event.into();
+        event.merge_context(&self.context);
+
+        self.reporter.report_event(event)
+    }
+
+    fn disconnect(self) -> Result<Self::DisconnectToken, Self::Err> {
+        Ok(())
+    }
+}
+
+/// An event, together with the context values of the [`ContextReporter`]s it was reported
+/// through.
+///
+/// Any event converts into a `WithContext` without context, so a reporter for
+/// `WithContext<C, E>` accepts plain `E` events.
+///
+/// [`ContextReporter`]: crate::ContextReporter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithContext<C, E> {
+    event: E,
+    contexts: Vec<C>,
+}
+
+impl<C, E> WithContext<C, E> {
+    /// The event.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// The context values, from the outermost to the innermost [`ContextReporter`].
+    ///
+    /// [`ContextReporter`]: crate::ContextReporter
+    pub fn contexts(&self) -> &[C] {
+        &self.contexts
+    }
+
+    /// Take the event and the context values out of the wrapper.
+    pub fn into_parts(self) -> (E, Vec<C>) {
+        (self.event, self.contexts)
+    }
+}
+
+impl<C, E> From<E> for WithContext<C, E> {
+    fn from(event: E) -> Self {
+        Self {
+            event,
+            contexts: Vec::new(),
+        }
+    }
+}
+
+impl<C: Clone, E> MergeContext<C> for WithContext<C, E> {
+    fn merge_context(&mut self, context: &C) {
+        // Outer contexts are merged last, but are listed first
+        self.contexts.insert(0, context.clone());
+    }
+}
@@ -0,0 +1,117 @@
+//! An object safe variant of the `EventReporter`.
+
+use crate::EventReporter;
+use std::any::Any;
+use std::error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// An object safe variant of the [`EventReporter`], which can be used as a trait object, for
+/// example as a `&dyn DynReporter<E>`.
+///
+/// It is implemented for every [`EventReporter`] whose event type can be created from `E`.
+/// Code which only reports events can accept a `&dyn DynReporter<E>`, instead of being generic
+/// over the reporter.
+///
+/// ```
+/// # #[cfg(feature = "std_channel")]
+/// # {
+/// use storyteller::{event_channel, ChannelReporter, DynReporter};
+///
+/// fn plugin(reporter: &dyn DynReporter<String>) {
+///     reporter.report("hello from a plugin".to_string()).unwrap();
+/// }
+///
+/// let (sender, receiver) = event_channel::<String>();
+/// let reporter = ChannelReporter::new(sender);
+///
+/// plugin(&reporter);
+///
+/// assert_eq!(receiver.recv().unwrap(), "hello from a plugin");
+/// # }
+/// ```
+///
+/// [`EventReporter`]: crate::EventReporter
+pub trait DynReporter<E> {
+    /// Send an event to listeners.
+    ///
+    /// The error of the underlying reporter is returned as a [`DynReporterError`].
+    ///
+    /// [`DynReporterError`]: crate::DynReporterError
+    fn report(&self, event: E) -> Result<(), DynReporterError>;
+}
+
+impl<R, E> DynReporter<E> for R
+where
+    R: EventReporter,
+    R::Err: Debug + Send + Sync + 'static,
+    E: Into<R::Event>,
+{
+    fn report(&self, event: E) -> Result<(), DynReporterError> {
+        self.report_event(event).map_err(DynReporterError::new)
+    }
+}
+
+/// The error of the reporter behind a [`DynReporter`].
+///
+/// The original error can be taken back with [`DynReporterError::downcast`], if its type is
+/// known.
+///
+/// [`DynReporter`]: crate::DynReporter
+/// [`DynReporterError::downcast`]: crate::DynReporterError::downcast
+pub struct DynReporterError {
+    err: Box<dyn AnyDebug>,
+}
+
+impl DynReporterError {
+    fn new<T: Debug + Send + Sync + 'static>(err: T) -> Self {
+        Self { err: Box::new(err) }
+    }
+
+    /// Take back the original error, if it is of type `T`.
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        if (*self.err).as_any().is::<T>() {
+            match self.err.into_any().downcast::<T>() {
+                Ok(err) => Ok(*err),
+                Err(_) => unreachable!("the type was checked before"),
+            }
+        } else {
+            Err(self)
+        }
+    }
+
+    /// A reference to the original error, if it is of type `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        (*self.err).as_any().downcast_ref::<T>()
+    }
+}
+
+impl Debug for DynReporterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.err, f)
+    }
+}
+
+impl Display for DynReporterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("unable to report event: {:?}", self.err))
+    }
+}
+
+impl error::Error for DynReporterError {}
+
+/// An error which can be formatted with `Debug`, and downcast to its original type.
+trait AnyDebug: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Debug + Send + Sync + 'static> AnyDebug for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
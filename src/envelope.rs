@@ -0,0 +1,104 @@
+//! An event wrapper which carries metadata about where and when the event was reported.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// The sequence number of the next envelope, shared by all envelopes in the process.
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// An event, together with metadata which is captured when the envelope is created.
+///
+/// The metadata consists of a sequence number, which is unique and increasing within the
+/// process, a wall-clock and a monotonic timestamp, and the name of the thread which created
+/// the envelope.
+///
+/// Any event can be converted into an envelope, so a reporter for `Envelope<E>` accepts plain
+/// `E` events, and fills in the metadata when the event is reported:
+///
+/// ```
+/// # #[cfg(feature = "std_channel")]
+/// # {
+/// use storyteller::{event_channel, ChannelReporter, Envelope, EventReporter};
+///
+/// let (sender, receiver) = event_channel::<Envelope<&str>>();
+/// let reporter = ChannelReporter::new(sender);
+///
+/// reporter.report_event("hello").unwrap();
+///
+/// let envelope = receiver.recv().unwrap();
+/// assert_eq!(*envelope.event(), "hello");
+/// # }
+/// ```
+///
+/// Use [`UnwrapEnvelope`] to run a handler for `E` on envelopes, or [`RenderEnvelope`] to
+/// turn the envelope, including its metadata, into an event for another handler.
+///
+/// [`UnwrapEnvelope`]: crate::UnwrapEnvelope
+/// [`RenderEnvelope`]: crate::RenderEnvelope
+#[derive(Debug, Clone)]
+pub struct Envelope<E> {
+    event: E,
+    sequence: u64,
+    timestamp: SystemTime,
+    instant: Instant,
+    thread_name: Option<String>,
+}
+
+impl<E> Envelope<E> {
+    /// Put the `event` in an envelope, capturing the metadata of the current thread.
+    pub fn new(event: E) -> Self {
+        Self {
+            event,
+            sequence: SEQUENCE.fetch_add(1, Ordering::SeqCst),
+            timestamp: SystemTime::now(),
+            instant: Instant::now(),
+            thread_name: thread::current().name().map(String::from),
+        }
+    }
+
+    /// The event in the envelope.
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// Take the event out of the envelope, discarding the metadata.
+    pub fn into_event(self) -> E {
+        self.event
+    }
+
+    /// The sequence number of the envelope.
+    ///
+    /// Envelopes created later have a higher sequence number, also when they are created on
+    /// different threads.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// The wall-clock time at which the envelope was created.
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+
+    /// The monotonic time at which the envelope was created.
+    pub fn instant(&self) -> Instant {
+        self.instant
+    }
+
+    /// The time which has passed since the envelope was created, for example to measure how
+    /// long an event waited before it was handled.
+    pub fn elapsed(&self) -> Duration {
+        self.instant.elapsed()
+    }
+
+    /// The name of the thread which created the envelope, if it has a name.
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+}
+
+impl<E> From<E> for Envelope<E> {
+    fn from(event: E) -> Self {
+        Self::new(event)
+    }
+}
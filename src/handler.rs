@@ -1,6 +1,11 @@
+use crate::handlers::combinators::{Filter, FilterMap, Inspect, Map};
+use std::sync::Arc;
+
 /// This can be anything, for example a progress bar, a fake reporter which collects events
-/// for testing, a service which sends the events over HTTP, or maybe even a `MultiHandler` which
-/// consists of a `Vec<Box<dyn EventHandler>>` and executes multiple handlers under the hood.
+/// for testing, a service which sends the events over HTTP, or maybe even a [`FanOutHandler`] which
+/// consists of multiple handlers and executes each of them under the hood.
+///
+/// [`FanOutHandler`]: crate::FanOutHandler
 pub trait EventHandler: Send + Sync {
     /// The type of event to be handled.
     /// Usually the same type as you would send from a [`EventReporter`] to [`EventListener`].
@@ -20,3 +25,152 @@ pub trait EventHandler: Send + Sync {
     /// [`EventListener`]: crate::EventListener
     fn finish(&self) {}
 }
+
+impl<H: EventHandler + ?Sized> EventHandler for Box<H> {
+    type Event = H::Event;
+
+    fn handle(&self, event: Self::Event) {
+        (**self).handle(event)
+    }
+
+    fn finish(&self) {
+        (**self).finish()
+    }
+}
+
+impl<H: EventHandler + ?Sized> EventHandler for Arc<H> {
+    type Event = H::Event;
+
+    fn handle(&self, event: Self::Event) {
+        (**self).handle(event)
+    }
+
+    fn finish(&self) {
+        (**self).finish()
+    }
+}
+
+impl<H: EventHandler + ?Sized> EventHandler for &'static H {
+    type Event = H::Event;
+
+    fn handle(&self, event: Self::Event) {
+        (**self).handle(event)
+    }
+
+    fn finish(&self) {
+        (**self).finish()
+    }
+}
+
+/// Combinators for every [`EventHandler`], which create a new handler on top of it.
+///
+/// Each of the created handlers forwards [`EventHandler::finish`] to the handler it was
+/// created from.
+///
+/// ```
+/// use storyteller::{handler_fn, EventHandler, EventHandlerExt};
+///
+/// enum AppEvent {
+///     Progress(u64),
+///     Message(String),
+/// }
+///
+/// let progress_bar = handler_fn(|position: u64| println!("{}%", position));
+///
+/// let handler = progress_bar.filter_map(|event: AppEvent| match event {
+///     AppEvent::Progress(position) => Some(position),
+///     _ => None,
+/// });
+///
+/// handler.handle(AppEvent::Message("ignored".to_string()));
+/// handler.handle(AppEvent::Progress(50));
+/// ```
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`EventHandler::finish`]: crate::EventHandler::finish
+pub trait EventHandlerExt: EventHandler + Sized {
+    /// Create a handler which only handles the events for which `predicate` returns `true`.
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
+    where
+        P: Fn(&Self::Event) -> bool + Send + Sync,
+    {
+        Filter::new(self, predicate)
+    }
+
+    /// Create a handler for events of type `E`, which maps each event with `map`, before
+    /// handling it with this handler.
+    fn map<E, F>(self, map: F) -> Map<Self, E, F>
+    where
+        F: Fn(E) -> Self::Event + Send + Sync,
+    {
+        Map::new(self, map)
+    }
+
+    /// Create a handler for events of type `E`, which maps each event with `filter_map`, and
+    /// handles the events which map to `Some` with this handler.
+    fn filter_map<E, F>(self, filter_map: F) -> FilterMap<Self, E, F>
+    where
+        F: Fn(E) -> Option<Self::Event> + Send + Sync,
+    {
+        FilterMap::new(self, filter_map)
+    }
+
+    /// Create a handler which calls `inspect` with a reference to each event, before handling
+    /// it with this handler.
+    fn inspect<F>(self, inspect: F) -> Inspect<Self, F>
+    where
+        F: Fn(&Self::Event) + Send + Sync,
+    {
+        Inspect::new(self, inspect)
+    }
+}
+
+impl<H: EventHandler> EventHandlerExt for H {}
+
+/// A variant of the [`EventHandler`], for handlers which can fail, for example because they
+/// write to a file.
+///
+/// Errors are collected by the listener, according to its [`ErrorPolicy`], and are returned
+/// when the handler is joined, see `ChannelEventListener::run_fallible_handler` (behind the
+/// `std_channel` feature).
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`ErrorPolicy`]: crate::ErrorPolicy
+pub trait FallibleEventHandler: Send + Sync {
+    /// The type of event to be handled.
+    /// Usually the same type as you would send from a [`EventReporter`] to [`EventListener`].
+    ///
+    /// [`EventReporter`]: crate::EventReporter
+    /// [`EventListener`]: crate::EventListener
+    type Event;
+
+    /// The type of error which occurs when an event can not be handled.
+    type Error;
+
+    /// Act upon some received event.
+    fn handle(&self, event: Self::Event) -> Result<(), Self::Error>;
+
+    /// A final action which can be performed when no more events will be received, for example
+    /// when the message channel will be disconnected.
+    ///
+    /// It is up to the listener to call this method.
+    fn finish(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Decides how a listener continues when a [`FallibleEventHandler`] returns an error.
+///
+/// [`FallibleEventHandler`]: crate::FallibleEventHandler
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ErrorPolicy {
+    /// Stop handling events after the first error.
+    ///
+    /// Subsequently received events are discarded, so reporters do not block on a full channel,
+    /// and [`FallibleEventHandler::finish`] is not called.
+    ///
+    /// [`FallibleEventHandler::finish`]: crate::FallibleEventHandler::finish
+    StopOnFirstError,
+    /// Keep handling events, and collect every error.
+    Collect,
+}
@@ -0,0 +1,53 @@
+use std::any::Any;
+use std::error;
+use std::fmt::{Display, Formatter};
+
+/// A panic of the handler thread, or handler task, caught when joining a `ChannelHandlerGuard`
+/// or a `TokioHandlerGuard`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HandlerPanic {
+    message: Option<String>,
+    events_handled: usize,
+}
+
+impl HandlerPanic {
+    pub(crate) fn new(payload: Box<dyn Any + Send>, events_handled: usize) -> Self {
+        // The payload of `panic!` is a `&'static str` for literal messages, and a `String`
+        // for formatted messages.
+        let message = match payload.downcast::<&'static str>() {
+            Ok(message) => Some(message.to_string()),
+            Err(payload) => payload.downcast::<String>().ok().map(|message| *message),
+        };
+
+        Self {
+            message,
+            events_handled,
+        }
+    }
+
+    /// The message the handler panicked with, if the panic payload was a string.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The number of events the handler finished handling, before it panicked.
+    pub fn events_handled(&self) -> usize {
+        self.events_handled
+    }
+}
+
+impl Display for HandlerPanic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "handler panicked after handling {} event(s)",
+            self.events_handled
+        ))?;
+
+        match &self.message {
+            Some(message) => f.write_fmt(format_args!(": {}", message)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl error::Error for HandlerPanic {}
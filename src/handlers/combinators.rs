@@ -0,0 +1,161 @@
+use crate::EventHandler;
+use std::marker::PhantomData;
+
+/// A handler which only passes the events for which a predicate holds on to its handler.
+/// Created by [`EventHandlerExt::filter`].
+///
+/// [`EventHandlerExt::filter`]: crate::EventHandlerExt::filter
+pub struct Filter<H, P> {
+    handler: H,
+    predicate: P,
+}
+
+impl<H, P> Filter<H, P> {
+    pub(crate) fn new(handler: H, predicate: P) -> Self {
+        Self { handler, predicate }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H, P> EventHandler for Filter<H, P>
+where
+    H: EventHandler,
+    P: Fn(&H::Event) -> bool + Send + Sync,
+{
+    type Event = H::Event;
+
+    fn handle(&self, event: Self::Event) {
+        if (self.predicate)(&event) {
+            self.handler.handle(event)
+        }
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
+
+/// A handler which maps each event to the event type of its handler.
+/// Created by [`EventHandlerExt::map`].
+///
+/// [`EventHandlerExt::map`]: crate::EventHandlerExt::map
+pub struct Map<H, E, F> {
+    handler: H,
+    map: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<H, E, F> Map<H, E, F> {
+    pub(crate) fn new(handler: H, map: F) -> Self {
+        Self {
+            handler,
+            map,
+            _event: PhantomData,
+        }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H, E, F> EventHandler for Map<H, E, F>
+where
+    H: EventHandler,
+    F: Fn(E) -> H::Event + Send + Sync,
+{
+    type Event = E;
+
+    fn handle(&self, event: Self::Event) {
+        self.handler.handle((self.map)(event))
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
+
+/// A handler which maps each event to the event type of its handler, and discards the events
+/// which map to `None`. Created by [`EventHandlerExt::filter_map`].
+///
+/// [`EventHandlerExt::filter_map`]: crate::EventHandlerExt::filter_map
+pub struct FilterMap<H, E, F> {
+    handler: H,
+    filter_map: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<H, E, F> FilterMap<H, E, F> {
+    pub(crate) fn new(handler: H, filter_map: F) -> Self {
+        Self {
+            handler,
+            filter_map,
+            _event: PhantomData,
+        }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H, E, F> EventHandler for FilterMap<H, E, F>
+where
+    H: EventHandler,
+    F: Fn(E) -> Option<H::Event> + Send + Sync,
+{
+    type Event = E;
+
+    fn handle(&self, event: Self::Event) {
+        if let Some(event) = (self.filter_map)(event) {
+            self.handler.handle(event)
+        }
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
+
+/// A handler which calls a closure with a reference to each event, before passing the event
+/// on to its handler. Created by [`EventHandlerExt::inspect`].
+///
+/// [`EventHandlerExt::inspect`]: crate::EventHandlerExt::inspect
+pub struct Inspect<H, F> {
+    handler: H,
+    inspect: F,
+}
+
+impl<H, F> Inspect<H, F> {
+    pub(crate) fn new(handler: H, inspect: F) -> Self {
+        Self { handler, inspect }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H, F> EventHandler for Inspect<H, F>
+where
+    H: EventHandler,
+    F: Fn(&H::Event) + Send + Sync,
+{
+    type Event = H::Event;
+
+    fn handle(&self, event: Self::Event) {
+        (self.inspect)(&event);
+        self.handler.handle(event)
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Envelope, EventHandler};
+use std::marker::PhantomData;
+
+/// A handler for [`Envelope`]s, which takes the event out of the envelope, and passes it on
+/// to a handler for the event.
+///
+/// [`Envelope`]: crate::Envelope
+pub struct UnwrapEnvelope<H> {
+    handler: H,
+}
+
+impl<H> UnwrapEnvelope<H> {
+    /// Create a handler which passes the events in envelopes on to `handler`.
+    pub fn new(handler: H) -> Self {
+        Self { handler }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<H: EventHandler> EventHandler for UnwrapEnvelope<H> {
+    type Event = Envelope<H::Event>;
+
+    fn handle(&self, event: Self::Event) {
+        self.handler.handle(event.into_event())
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
+
+/// A handler for [`Envelope`]s, which renders each envelope into an event for another
+/// handler, for example a line of output which includes the metadata of the envelope.
+///
+/// ```
+/// use storyteller::{Envelope, EventHandler, RenderEnvelope};
+///
+/// struct PrintHandler;
+///
+/// impl EventHandler for PrintHandler {
+///     type Event = String;
+///
+///     fn handle(&self, event: Self::Event) {
+///         println!("{}", event);
+///     }
+/// }
+///
+/// let handler = RenderEnvelope::new(PrintHandler, |envelope: Envelope<&str>| {
+///     format!("#{} {}", envelope.sequence(), envelope.event())
+/// });
+///
+/// handler.handle(Envelope::new("hello"));
+/// ```
+///
+/// [`Envelope`]: crate::Envelope
+pub struct RenderEnvelope<E, H, F> {
+    handler: H,
+    render: F,
+    _event: PhantomData<fn(Envelope<E>)>,
+}
+
+impl<E, H, F> RenderEnvelope<E, H, F>
+where
+    H: EventHandler,
+    F: Fn(Envelope<E>) -> H::Event,
+{
+    /// Create a handler which renders each envelope with `render`, and passes the result on
+    /// to `handler`.
+    pub fn new(handler: H, render: F) -> Self {
+        Self {
+            handler,
+            render,
+            _event: PhantomData,
+        }
+    }
+
+    /// Take back the inner handler.
+    pub fn into_inner(self) -> H {
+        self.handler
+    }
+}
+
+impl<E, H, F> EventHandler for RenderEnvelope<E, H, F>
+where
+    H: EventHandler,
+    F: Fn(Envelope<E>) -> H::Event + Send + Sync,
+{
+    type Event = Envelope<E>;
+
+    fn handle(&self, event: Self::Event) {
+        self.handler.handle((self.render)(event))
+    }
+
+    fn finish(&self) {
+        self.handler.finish()
+    }
+}
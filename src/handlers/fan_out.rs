@@ -0,0 +1,80 @@
+use crate::handlers::AnyEventHandler;
+use crate::EventHandler;
+
+/// A handler which runs several [`EventHandler`]s for each event.
+///
+/// Each event is cloned for every handler, and `finish` is forwarded to every handler,
+/// in the order in which the handlers were added.
+///
+/// The handlers can be retrieved again with their concrete type, for example to inspect them
+/// once all events have been handled.
+///
+/// [`EventHandler`]: crate::EventHandler
+pub struct FanOutHandler<Event> {
+    handlers: Vec<Box<dyn AnyEventHandler<Event>>>,
+}
+
+impl<Event> FanOutHandler<Event> {
+    /// Create a handler which does not hold any handlers yet.
+    pub fn new() -> Self {
+        Self { handlers: vec![] }
+    }
+
+    /// Add a handler, which will receive every subsequent event.
+    ///
+    /// Returns the index of the handler, which can be used to retrieve it with
+    /// [`FanOutHandler::get`].
+    ///
+    /// [`FanOutHandler::get`]: crate::FanOutHandler::get
+    pub fn add_handler<H>(&mut self, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        self.handlers.push(Box::new(handler));
+        self.handlers.len() - 1
+    }
+
+    /// Get the `nth` handler, if it exists and is of type `H`.
+    pub fn get<H>(&self, nth: usize) -> Option<&H>
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        self.handlers
+            .get(nth)
+            // NB: `as_any` is called on the handler in the box, not on the box itself, which is
+            //  an `EventHandler` as well.
+            .and_then(|handler| (**handler).as_any().downcast_ref::<H>())
+    }
+
+    /// The number of handlers held by this handler.
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Whether this handler holds no handlers.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+}
+
+impl<Event> Default for FanOutHandler<Event> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Event: Clone> EventHandler for FanOutHandler<Event> {
+    type Event = Event;
+
+    fn handle(&self, event: Self::Event) {
+        for handler in &self.handlers {
+            handler.handle(event.clone());
+        }
+    }
+
+    fn finish(&self) {
+        for handler in &self.handlers {
+            handler.finish();
+        }
+    }
+}
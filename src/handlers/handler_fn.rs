@@ -0,0 +1,86 @@
+use crate::EventHandler;
+use std::marker::PhantomData;
+
+/// Create an [`EventHandler`] from a closure, which is called for each event.
+///
+/// ```
+/// use storyteller::{handler_fn, EventHandler};
+///
+/// let handler = handler_fn(|event: String| println!("{}", event));
+///
+/// handler.handle("hello".to_string());
+/// ```
+///
+/// [`EventHandler`]: crate::EventHandler
+pub fn handler_fn<Event, F>(handle: F) -> HandlerFn<Event, F>
+where
+    F: Fn(Event) + Send + Sync,
+{
+    HandlerFn {
+        handle,
+        _event: PhantomData,
+    }
+}
+
+/// Create an [`EventHandler`] from two closures: `handle`, which is called for each event, and
+/// `finish`, which is called when no more events will be received.
+///
+/// [`EventHandler`]: crate::EventHandler
+pub fn handler_fn_with_finish<Event, F, G>(handle: F, finish: G) -> HandlerFnWithFinish<Event, F, G>
+where
+    F: Fn(Event) + Send + Sync,
+    G: Fn() + Send + Sync,
+{
+    HandlerFnWithFinish {
+        handle,
+        finish,
+        _event: PhantomData,
+    }
+}
+
+/// An [`EventHandler`] which calls a closure for each event. Created by [`handler_fn`].
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`handler_fn`]: crate::handler_fn
+pub struct HandlerFn<Event, F> {
+    handle: F,
+    _event: PhantomData<fn(Event)>,
+}
+
+impl<Event, F> EventHandler for HandlerFn<Event, F>
+where
+    F: Fn(Event) + Send + Sync,
+{
+    type Event = Event;
+
+    fn handle(&self, event: Self::Event) {
+        (self.handle)(event)
+    }
+}
+
+/// An [`EventHandler`] which calls a closure for each event, and another closure when it
+/// finishes. Created by [`handler_fn_with_finish`].
+///
+/// [`EventHandler`]: crate::EventHandler
+/// [`handler_fn_with_finish`]: crate::handler_fn_with_finish
+pub struct HandlerFnWithFinish<Event, F, G> {
+    handle: F,
+    finish: G,
+    _event: PhantomData<fn(Event)>,
+}
+
+impl<Event, F, G> EventHandler for HandlerFnWithFinish<Event, F, G>
+where
+    F: Fn(Event) + Send + Sync,
+    G: Fn() + Send + Sync,
+{
+    type Event = Event;
+
+    fn handle(&self, event: Self::Event) {
+        (self.handle)(event)
+    }
+
+    fn finish(&self) {
+        (self.finish)()
+    }
+}
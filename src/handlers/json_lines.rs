@@ -0,0 +1,219 @@
+use crate::EventHandler;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::{error, io};
+
+/// A handler which writes each event as a line of JSON ([JSON Lines]), to any writer, such as
+/// `stdout`, `stderr` or a file.
+///
+/// Optionally, each event can be wrapped in an envelope with the name and version of the
+/// schema of the output, see [`JsonLinesHandler::with_schema`]. The writer is flushed when the
+/// handler finishes.
+///
+/// When an event can't be serialized, or a line can't be written, the [`JsonErrorPolicy`] of
+/// the handler decides what happens with the error.
+///
+/// ```
+/// use storyteller::{EventHandler, JsonLinesHandler};
+///
+/// #[derive(serde::Serialize)]
+/// #[serde(tag = "type", rename_all = "snake_case")]
+/// enum Event {
+///     DiceThrow { throw: u8 },
+/// }
+///
+/// let handler = JsonLinesHandler::new(Vec::new()).with_schema("dice", 1);
+///
+/// handler.handle(Event::DiceThrow { throw: 6 });
+/// handler.finish();
+///
+/// let output = String::from_utf8(handler.into_inner()).unwrap();
+/// assert_eq!(
+///     output,
+///     "{\"schema\":\"dice\",\"version\":1,\"event\":{\"type\":\"dice_throw\",\"throw\":6}}\n"
+/// );
+/// ```
+///
+/// Requires the `json` feature.
+///
+/// [JSON Lines]: https://jsonlines.org
+/// [`JsonLinesHandler::with_schema`]: crate::JsonLinesHandler::with_schema
+/// [`JsonErrorPolicy`]: crate::JsonErrorPolicy
+pub struct JsonLinesHandler<E, W> {
+    writer: Mutex<W>,
+    schema: Option<Schema>,
+    policy: JsonErrorPolicy,
+    errors: Mutex<Vec<JsonLinesError>>,
+    _event: PhantomData<fn(E)>,
+}
+
+struct Schema {
+    name: String,
+    version: u32,
+}
+
+#[derive(Serialize)]
+struct Versioned<'a, E> {
+    schema: &'a str,
+    version: u32,
+    event: &'a E,
+}
+
+impl<E, W> JsonLinesHandler<E, W>
+where
+    E: Serialize,
+    W: Write + Send,
+{
+    /// Create a handler which writes events to `writer`.
+    ///
+    /// Errors are discarded, see [`JsonErrorPolicy::Skip`].
+    ///
+    /// [`JsonErrorPolicy::Skip`]: crate::JsonErrorPolicy::Skip
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            schema: None,
+            policy: JsonErrorPolicy::Skip,
+            errors: Mutex::new(Vec::new()),
+            _event: PhantomData,
+        }
+    }
+
+    /// Wrap each event in an envelope with the `name` and `version` of the schema of the output:
+    /// `{"schema":"<name>","version":<version>,"event":<event>}`.
+    pub fn with_schema(mut self, name: impl Into<String>, version: u32) -> Self {
+        self.schema = Some(Schema {
+            name: name.into(),
+            version,
+        });
+        self
+    }
+
+    /// Decide what happens when an event can't be serialized or written.
+    pub fn with_error_policy(mut self, policy: JsonErrorPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Take the errors which were collected so far, with [`JsonErrorPolicy::Collect`].
+    ///
+    /// [`JsonErrorPolicy::Collect`]: crate::JsonErrorPolicy::Collect
+    pub fn take_errors(&self) -> Vec<JsonLinesError> {
+        let mut errors = self.errors.lock().unwrap_or_else(|err| err.into_inner());
+        std::mem::take(&mut *errors)
+    }
+
+    /// Take back the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn write_event(&self, event: &E) -> Result<(), JsonLinesError> {
+        // The line is serialized up front, so a failure to serialize doesn't leave half a line
+        // in the output.
+        let mut line = match &self.schema {
+            Some(schema) => serde_json::to_vec(&Versioned {
+                schema: &schema.name,
+                version: schema.version,
+                event,
+            }),
+            None => serde_json::to_vec(event),
+        }
+        .map_err(JsonLinesError::Serialize)?;
+
+        line.push(b'\n');
+
+        let mut writer = self.writer.lock().unwrap_or_else(|err| err.into_inner());
+        writer.write_all(&line).map_err(JsonLinesError::Io)
+    }
+
+    fn flush(&self) -> Result<(), JsonLinesError> {
+        let mut writer = self.writer.lock().unwrap_or_else(|err| err.into_inner());
+        writer.flush().map_err(JsonLinesError::Io)
+    }
+
+    fn on_error(&self, err: JsonLinesError) {
+        match self.policy {
+            JsonErrorPolicy::Skip => {}
+            JsonErrorPolicy::Collect => self
+                .errors
+                .lock()
+                .unwrap_or_else(|err| err.into_inner())
+                .push(err),
+            JsonErrorPolicy::Panic => panic!("{}", err),
+        }
+    }
+}
+
+impl<E, W> EventHandler for JsonLinesHandler<E, W>
+where
+    E: Serialize,
+    W: Write + Send,
+{
+    type Event = E;
+
+    fn handle(&self, event: Self::Event) {
+        if let Err(err) = self.write_event(&event) {
+            self.on_error(err);
+        }
+    }
+
+    fn finish(&self) {
+        if let Err(err) = self.flush() {
+            self.on_error(err);
+        }
+    }
+}
+
+/// Decides what a [`JsonLinesHandler`] does, when an event can't be serialized or written.
+///
+/// [`JsonLinesHandler`]: crate::JsonLinesHandler
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum JsonErrorPolicy {
+    /// Discard the error, and continue with the next event.
+    Skip,
+    /// Keep the error, and continue with the next event. The errors can be taken with
+    /// [`JsonLinesHandler::take_errors`].
+    ///
+    /// [`JsonLinesHandler::take_errors`]: crate::JsonLinesHandler::take_errors
+    Collect,
+    /// Panic. When the handler runs in a thread of the [`ChannelEventListener`], the panic is
+    /// returned when the handler is joined.
+    ///
+    /// [`ChannelEventListener`]: crate::ChannelEventListener
+    Panic,
+}
+
+/// The reason why a [`JsonLinesHandler`] couldn't write an event.
+///
+/// [`JsonLinesHandler`]: crate::JsonLinesHandler
+#[derive(Debug)]
+pub enum JsonLinesError {
+    /// The event couldn't be serialized to JSON.
+    Serialize(serde_json::Error),
+    /// The writer failed.
+    Io(io::Error),
+}
+
+impl Display for JsonLinesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serialize(err) => f.write_fmt(format_args!("unable to serialize event: {}", err)),
+            Self::Io(err) => f.write_fmt(format_args!("unable to write event: {}", err)),
+        }
+    }
+}
+
+impl error::Error for JsonLinesError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Serialize(err) => Some(err),
+            Self::Io(err) => Some(err),
+        }
+    }
+}
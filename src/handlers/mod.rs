@@ -0,0 +1,26 @@
+use crate::EventHandler;
+use std::any::Any;
+
+pub mod combinators;
+pub mod envelope;
+pub mod fan_out;
+pub mod handler_fn;
+#[cfg(feature = "json")]
+pub mod json_lines;
+#[cfg(feature = "indicatif")]
+pub mod progress;
+pub mod router;
+
+/// An [`EventHandler`] which can be downcast to its concrete type.
+pub(crate) trait AnyEventHandler<Event>: EventHandler<Event = Event> {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<H> AnyEventHandler<H::Event> for H
+where
+    H: EventHandler + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
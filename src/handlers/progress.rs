@@ -0,0 +1,200 @@
+use crate::EventHandler;
+use indicatif::{MultiProgress, ProgressBar};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+/// A handler which shows events as progress bars, using [`indicatif`].
+///
+/// Which progress bar an event updates, and how, is decided by a [`ProgressMapping`]. Each task
+/// of the mapping has its own progress bar, which is created the first time the task is updated,
+/// so concurrent tasks are shown as multiple bars of a [`MultiProgress`]. A program with a
+/// single progress bar can use `()` as its task.
+///
+/// When the handler finishes, the progress bars which haven't been finished yet are finished or
+/// abandoned, see [`ProgressMapping::finish_bar`].
+///
+/// ```
+/// use indicatif::{MultiProgress, ProgressDrawTarget};
+/// use storyteller::{EventHandler, ProgressHandler, ProgressMapping, ProgressUpdate};
+///
+/// enum Event {
+///     Found(u64),
+///     Downloaded,
+/// }
+///
+/// struct Downloads;
+///
+/// impl ProgressMapping for Downloads {
+///     type Event = Event;
+///     type Task = ();
+///
+///     fn map(&self, event: Self::Event) -> Vec<ProgressUpdate<()>> {
+///         match event {
+///             Event::Found(count) => vec![ProgressUpdate::SetLength { task: (), length: count }],
+///             Event::Downloaded => vec![ProgressUpdate::Increment { task: (), delta: 1 }],
+///         }
+///     }
+/// }
+///
+/// let multi = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+/// let handler = ProgressHandler::with_multi_progress(Downloads, multi);
+///
+/// handler.handle(Event::Found(2));
+/// handler.handle(Event::Downloaded);
+/// handler.handle(Event::Downloaded);
+/// handler.finish();
+///
+/// let bar = handler.bar(&()).unwrap();
+/// assert_eq!(bar.position(), 2);
+/// assert!(bar.is_finished());
+/// ```
+///
+/// Requires the `indicatif` feature.
+///
+/// [`indicatif`]: https://docs.rs/indicatif
+/// [`ProgressMapping`]: crate::ProgressMapping
+/// [`MultiProgress`]: indicatif::MultiProgress
+/// [`ProgressMapping::finish_bar`]: crate::ProgressMapping::finish_bar
+pub struct ProgressHandler<M: ProgressMapping> {
+    mapping: M,
+    multi: MultiProgress,
+    bars: Mutex<HashMap<M::Task, ProgressBar>>,
+}
+
+impl<M: ProgressMapping> ProgressHandler<M> {
+    /// Create a handler which draws its progress bars to `stderr`.
+    pub fn new(mapping: M) -> Self {
+        Self::with_multi_progress(mapping, MultiProgress::new())
+    }
+
+    /// Create a handler which adds its progress bars to `multi`.
+    ///
+    /// Use this to draw the progress bars to another target, or to show them together with
+    /// progress bars which are not managed by this handler.
+    pub fn with_multi_progress(mapping: M, multi: MultiProgress) -> Self {
+        Self {
+            mapping,
+            multi,
+            bars: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The [`MultiProgress`] to which the progress bars are added.
+    ///
+    /// [`MultiProgress`]: indicatif::MultiProgress
+    pub fn multi_progress(&self) -> &MultiProgress {
+        &self.multi
+    }
+
+    /// The progress bar of `task`, if the task has been updated at least once.
+    pub fn bar(&self, task: &M::Task) -> Option<ProgressBar> {
+        let bars = self.bars.lock().unwrap_or_else(|err| err.into_inner());
+        bars.get(task).cloned()
+    }
+
+    fn update(&self, update: ProgressUpdate<M::Task>) {
+        match update {
+            ProgressUpdate::Increment { task, delta } => self.bar_of(task).inc(delta),
+            ProgressUpdate::SetLength { task, length } => self.bar_of(task).set_length(length),
+            ProgressUpdate::SetMessage { task, message } => self.bar_of(task).set_message(message),
+            ProgressUpdate::Reset { task } => self.bar_of(task).reset(),
+            ProgressUpdate::Finish { task } => self.bar_of(task).finish(),
+            ProgressUpdate::Abandon { task } => self.bar_of(task).abandon(),
+            ProgressUpdate::Println(message) => {
+                // Printing is best effort; a failure to draw should not stop the handler.
+                let _ = self.multi.println(message);
+            }
+        }
+    }
+
+    /// The progress bar of `task`, which is created if it doesn't exist yet.
+    fn bar_of(&self, task: M::Task) -> ProgressBar {
+        let mut bars = self.bars.lock().unwrap_or_else(|err| err.into_inner());
+
+        bars.entry(task)
+            .or_insert_with_key(|task| self.multi.add(self.mapping.new_bar(task)))
+            .clone()
+    }
+}
+
+impl<M: ProgressMapping> EventHandler for ProgressHandler<M> {
+    type Event = M::Event;
+
+    fn handle(&self, event: Self::Event) {
+        for update in self.mapping.map(event) {
+            self.update(update);
+        }
+    }
+
+    fn finish(&self) {
+        let bars = self.bars.lock().unwrap_or_else(|err| err.into_inner());
+
+        for (task, bar) in bars.iter().filter(|(_, bar)| !bar.is_finished()) {
+            self.mapping.finish_bar(task, bar);
+        }
+    }
+}
+
+/// Decides how a [`ProgressHandler`] shows events.
+///
+/// [`ProgressHandler`]: crate::ProgressHandler
+pub trait ProgressMapping: Send + Sync {
+    /// The type of event to be shown.
+    type Event;
+
+    /// Identifies a progress bar. Each task has its own progress bar.
+    ///
+    /// Use `()` when there's only a single progress bar.
+    type Task: Eq + Hash + Send;
+
+    /// Map an event to the updates of the progress bars. The updates are applied in order.
+    fn map(&self, event: Self::Event) -> Vec<ProgressUpdate<Self::Task>>;
+
+    /// Create the progress bar of `task`, when it's updated for the first time.
+    ///
+    /// The default creates a bar with a length of `0`; the length can be set with
+    /// [`ProgressUpdate::SetLength`]. Override this method to give the bar a style.
+    ///
+    /// [`ProgressUpdate::SetLength`]: crate::ProgressUpdate::SetLength
+    fn new_bar(&self, task: &Self::Task) -> ProgressBar {
+        let _ = task;
+        ProgressBar::new(0)
+    }
+
+    /// Called for each progress bar which hasn't been finished yet, when the handler finishes.
+    ///
+    /// The default finishes the bars which have reached their length, and abandons the others,
+    /// so the bars of incomplete tasks are left as they are.
+    fn finish_bar(&self, task: &Self::Task, bar: &ProgressBar) {
+        let _ = task;
+
+        match bar.length() {
+            Some(length) if bar.position() < length => bar.abandon(),
+            _ => bar.finish(),
+        }
+    }
+}
+
+/// An update of a progress bar of a [`ProgressHandler`], created by a [`ProgressMapping`].
+///
+/// [`ProgressHandler`]: crate::ProgressHandler
+/// [`ProgressMapping`]: crate::ProgressMapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressUpdate<T> {
+    /// Advance the position of the bar of `task` by `delta`.
+    Increment { task: T, delta: u64 },
+    /// Set the length of the bar of `task`.
+    SetLength { task: T, length: u64 },
+    /// Set the message shown with the bar of `task`.
+    SetMessage { task: T, message: Cow<'static, str> },
+    /// Reset the position and elapsed time of the bar of `task`.
+    Reset { task: T },
+    /// Finish the bar of `task`, for example when a concurrent task completes.
+    Finish { task: T },
+    /// Abandon the bar of `task`, leaving it as it is, for example when a concurrent task fails.
+    Abandon { task: T },
+    /// Print a line above the progress bars.
+    Println(Cow<'static, str>),
+}
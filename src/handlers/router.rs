@@ -0,0 +1,189 @@
+use crate::handlers::AnyEventHandler;
+use crate::EventHandler;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A handler which routes each event to the handlers registered for its route.
+///
+/// The route of an event is a key, decided by a classifier function, for example the variant
+/// of an event enum. Each event is cloned for every handler it is routed to.
+///
+/// Handlers can be registered for one or more routes, for every event, or as a fallback for
+/// events for which no route has been registered. `finish` is called once on every registered
+/// handler, in the order in which the handlers were registered.
+///
+/// ```
+/// use storyteller::{handler_fn, EventHandler, RouterHandler};
+///
+/// #[derive(Clone)]
+/// enum AppEvent {
+///     Download(u64),
+///     Diagnostic(String),
+/// }
+///
+/// #[derive(PartialEq, Eq, Hash)]
+/// enum Route {
+///     Download,
+///     Diagnostic,
+/// }
+///
+/// let mut router = RouterHandler::new(|event: &AppEvent| match event {
+///     AppEvent::Download(_) => Route::Download,
+///     AppEvent::Diagnostic(_) => Route::Diagnostic,
+/// });
+///
+/// router.add_route(Route::Download, handler_fn(|_: AppEvent| { /* progress bar */ }));
+/// router.add_route(Route::Diagnostic, handler_fn(|_: AppEvent| { /* pretty printer */ }));
+/// router.add_catch_all(handler_fn(|_: AppEvent| { /* json log */ }));
+///
+/// router.handle(AppEvent::Download(1024));
+/// router.finish();
+/// ```
+pub struct RouterHandler<Event, K> {
+    classify: Box<dyn Fn(&Event) -> K + Send + Sync>,
+    handlers: Vec<Box<dyn AnyEventHandler<Event>>>,
+    routes: HashMap<K, Vec<usize>>,
+    catch_all: Vec<usize>,
+    fallback: Vec<usize>,
+}
+
+impl<Event, K> RouterHandler<Event, K>
+where
+    K: Eq + Hash,
+{
+    /// Create a router, which uses `classify` to decide the route of each event.
+    pub fn new<F>(classify: F) -> Self
+    where
+        F: Fn(&Event) -> K + Send + Sync + 'static,
+    {
+        Self {
+            classify: Box::new(classify),
+            handlers: vec![],
+            routes: HashMap::new(),
+            catch_all: vec![],
+            fallback: vec![],
+        }
+    }
+
+    /// Add a handler, which will receive the events of the given route.
+    ///
+    /// Returns the index of the handler, which can be used to retrieve it with
+    /// [`RouterHandler::get`].
+    ///
+    /// [`RouterHandler::get`]: crate::RouterHandler::get
+    pub fn add_route<H>(&mut self, route: K, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        self.add_routes(Some(route), handler)
+    }
+
+    /// Add a handler, which will receive the events of each of the given routes.
+    ///
+    /// Returns the index of the handler, which can be used to retrieve it with
+    /// [`RouterHandler::get`].
+    ///
+    /// [`RouterHandler::get`]: crate::RouterHandler::get
+    pub fn add_routes<H, I>(&mut self, routes: I, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+        I: IntoIterator<Item = K>,
+    {
+        let index = self.register(handler);
+
+        for route in routes {
+            let handlers = self.routes.entry(route).or_default();
+
+            if !handlers.contains(&index) {
+                handlers.push(index);
+            }
+        }
+
+        index
+    }
+
+    /// Add a handler, which will receive every event, regardless of its route.
+    ///
+    /// Returns the index of the handler, which can be used to retrieve it with
+    /// [`RouterHandler::get`].
+    ///
+    /// [`RouterHandler::get`]: crate::RouterHandler::get
+    pub fn add_catch_all<H>(&mut self, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        let index = self.register(handler);
+        self.catch_all.push(index);
+        index
+    }
+
+    /// Add a handler, which will receive the events for whose route no handler was added.
+    ///
+    /// Returns the index of the handler, which can be used to retrieve it with
+    /// [`RouterHandler::get`].
+    ///
+    /// [`RouterHandler::get`]: crate::RouterHandler::get
+    pub fn add_fallback<H>(&mut self, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        let index = self.register(handler);
+        self.fallback.push(index);
+        index
+    }
+
+    /// Get the `nth` handler, if it exists and is of type `H`.
+    pub fn get<H>(&self, nth: usize) -> Option<&H>
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        self.handlers
+            .get(nth)
+            .and_then(|handler| (**handler).as_any().downcast_ref::<H>())
+    }
+
+    /// The number of handlers registered with this router.
+    pub fn len(&self) -> usize {
+        self.handlers.len()
+    }
+
+    /// Whether no handlers are registered with this router.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+
+    fn register<H>(&mut self, handler: H) -> usize
+    where
+        H: EventHandler<Event = Event> + 'static,
+    {
+        self.handlers.push(Box::new(handler));
+        self.handlers.len() - 1
+    }
+}
+
+impl<Event, K> EventHandler for RouterHandler<Event, K>
+where
+    Event: Clone,
+    K: Eq + Hash + Send + Sync,
+{
+    type Event = Event;
+
+    fn handle(&self, event: Self::Event) {
+        let route = (self.classify)(&event);
+
+        let routed = match self.routes.get(&route) {
+            Some(handlers) => handlers.as_slice(),
+            None => self.fallback.as_slice(),
+        };
+
+        for &index in self.catch_all.iter().chain(routed) {
+            self.handlers[index].handle(event.clone());
+        }
+    }
+
+    fn finish(&self) {
+        for handler in &self.handlers {
+            handler.finish();
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::inline_reporter::HandlerSlot;
+use crate::{EventHandler, EventListener, HandlerGuard};
+use std::convert::Infallible;
+use std::sync::Arc;
+
+/// A listener which does not listen by itself: it hands the handler to the paired
+/// [`InlineReporter`], which handles events on the thread which reports them.
+///
+/// Both can be created by calling the [`inline_pair()`] function.
+///
+/// Running another handler replaces the previous handler. The [`InlineHandlerGuard`] of the
+/// previous handler can still be joined, to finish it.
+///
+/// [`InlineReporter`]: crate::InlineReporter
+/// [`inline_pair()`]: crate::inline_pair
+/// [`InlineHandlerGuard`]: crate::InlineHandlerGuard
+pub struct InlineEventListener<Event> {
+    handler: HandlerSlot<Event>,
+}
+
+impl<Event> InlineEventListener<Event> {
+    pub(crate) fn new(handler: HandlerSlot<Event>) -> Self {
+        Self { handler }
+    }
+}
+
+impl<Event> EventListener for InlineEventListener<Event>
+where
+    Event: 'static,
+{
+    type Event = Event;
+    type Guard = InlineHandlerGuard<Event>;
+
+    fn run_handler<H>(&self, handler: Arc<H>) -> Self::Guard
+    where
+        H: EventHandler<Event = Self::Event> + 'static,
+    {
+        let handler: Arc<dyn EventHandler<Event = Event>> = handler;
+
+        *self.handler.write().unwrap_or_else(|err| err.into_inner()) = Some(handler.clone());
+
+        InlineHandlerGuard { handler }
+    }
+}
+
+/// A [`HandlerGuard`] for the [`InlineEventListener`].
+///
+/// Since events have already been handled by the time they have been reported, joining the
+/// guard only calls [`EventHandler::finish`].
+///
+/// [`HandlerGuard`]: crate::HandlerGuard
+/// [`InlineEventListener`]: crate::InlineEventListener
+/// [`EventHandler::finish`]: crate::EventHandler::finish
+#[must_use]
+pub struct InlineHandlerGuard<Event> {
+    handler: Arc<dyn EventHandler<Event = Event>>,
+}
+
+impl<Event> HandlerGuard for InlineHandlerGuard<Event> {
+    type Err = Infallible;
+    type Token = ();
+
+    fn join(self, _token: ()) -> Result<(), Self::Err> {
+        self.handler.finish();
+        Ok(())
+    }
+}
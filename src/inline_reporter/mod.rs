@@ -0,0 +1,30 @@
+pub mod listener;
+pub mod reporter;
+
+use crate::EventHandler;
+use std::sync::{Arc, RwLock};
+
+/// The handler which is run by the `InlineEventListener`, shared with the `InlineReporter`.
+type HandlerSlot<Event> = Arc<RwLock<Option<Arc<dyn EventHandler<Event = Event>>>>>;
+
+/// A reporter and listener pair, which handle events on the thread which reports them.
+///
+/// The [`InlineReporter`] calls the handler, run by the [`InlineEventListener`], directly
+/// from [`EventReporter::report_event`]. No threads are spawned, and events are handled
+/// in the order in which they are reported, which makes this pair a good fit for simple
+/// programs and tests.
+///
+/// [`InlineReporter`]: crate::InlineReporter
+/// [`InlineEventListener`]: crate::InlineEventListener
+/// [`EventReporter::report_event`]: crate::EventReporter::report_event
+pub fn inline_pair<Event>() -> (
+    reporter::InlineReporter<Event>,
+    listener::InlineEventListener<Event>,
+) {
+    let slot: HandlerSlot<Event> = Arc::new(RwLock::new(None));
+
+    (
+        reporter::InlineReporter::new(slot.clone()),
+        listener::InlineEventListener::new(slot),
+    )
+}
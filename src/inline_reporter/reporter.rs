@@ -0,0 +1,110 @@
+use crate::inline_reporter::HandlerSlot;
+use crate::EventReporter;
+use std::error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// A reporter which handles events directly, on the thread which reports them.
+///
+/// Events are handled by the handler run by the paired [`InlineEventListener`]. Both can be
+/// created by calling the [`inline_pair()`] function.
+///
+/// Since the handler has finished handling an event by the time [`EventReporter::report_event`]
+/// returns, there is no ordering requirement between [`EventReporter::disconnect`] and
+/// [`HandlerGuard::join`], and the `DisconnectToken` is `()`.
+///
+/// [`InlineEventListener`]: crate::InlineEventListener
+/// [`inline_pair()`]: crate::inline_pair
+/// [`EventReporter::report_event`]: crate::EventReporter::report_event
+/// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+/// [`HandlerGuard::join`]: crate::HandlerGuard::join
+pub struct InlineReporter<Event> {
+    handler: HandlerSlot<Event>,
+}
+
+impl<Event> InlineReporter<Event> {
+    pub(crate) fn new(handler: HandlerSlot<Event>) -> Self {
+        Self { handler }
+    }
+}
+
+impl<Event> EventReporter for InlineReporter<Event> {
+    type Event = Event;
+    type Err = InlineReporterError<Event>;
+    type DisconnectToken = ();
+
+    /// Handle the event, using the handler run by the [`InlineEventListener`].
+    ///
+    /// The handler may run another handler on the listener while it handles the event; the
+    /// other handler handles the events which are reported afterwards.
+    ///
+    /// [`InlineEventListener`]: crate::InlineEventListener
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        // The handler is taken out of the lock before it runs, so it can run another handler
+        // on the paired listener without deadlocking.
+        let handler = self
+            .handler
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone();
+
+        match handler {
+            Some(handler) => {
+                handler.handle(event.into());
+                Ok(())
+            }
+            None => Err(InlineReporterError::NoHandler(event.into())),
+        }
+    }
+
+    fn disconnect(self) -> Result<(), Self::Err> {
+        Ok(())
+    }
+}
+
+/// The reason why an event could not be reported by the [`InlineReporter`]. The event is
+/// handed back.
+///
+/// [`InlineReporter`]: crate::InlineReporter
+pub enum InlineReporterError<Event> {
+    /// No handler is running yet, see [`EventListener::run_handler`].
+    ///
+    /// [`EventListener::run_handler`]: crate::EventListener::run_handler
+    NoHandler(Event),
+}
+
+impl<Event> InlineReporterError<Event> {
+    /// Take back the event which could not be reported.
+    pub fn into_event(self) -> Event {
+        match self {
+            Self::NoHandler(event) => event,
+        }
+    }
+}
+
+impl<Event> Debug for InlineReporterError<Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoHandler(_) => f.write_fmt(format_args!(
+                "NoHandler({})",
+                std::any::type_name::<Event>()
+            )),
+        }
+    }
+}
+
+impl<Event> Display for InlineReporterError<Event>
+where
+    Event: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoHandler(ev) => f.write_fmt(format_args!(
+                "NoHandler({} = '{}')",
+                std::any::type_name::<Event>(),
+                ev
+            )),
+        }
+    }
+}
+
+impl<Event> error::Error for InlineReporterError<Event> where Event: Display {}
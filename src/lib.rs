@@ -19,28 +19,92 @@
 //! in a separate thread.
 //! To use this implementation, consult the docs for the [`ChannelReporter`],
 //! and the [`ChannelEventListener`].
+//! The `scope` function sets up both, and disconnects and joins them when its closure returns.
+//!
+//! The channel is a crossbeam channel when the `channel_reporter` feature is enabled (default).
+//! When only the `std_channel` feature is enabled, a `std::sync::mpsc` channel is used instead,
+//! with the same semantics.
+//!
+//! For programs which do not need a separate thread, the [`InlineReporter`] handles each event
+//! on the thread which reports it. It does not require the `channel_reporter` feature.
 //!
 //! [`EventHandler`]: crate::EventHandler
 //! [`EventReporter`]: crate::EventReporter
 //! [`EventListener`]: `crate::EventListener`
 //! [`ChannelReporter`]: crate::ChannelReporter
 //! [`ChannelEventListener`]: crate::ChannelEventListener
+//! [`InlineReporter`]: crate::InlineReporter
 
+#[cfg(feature = "async")]
+mod async_handler;
+mod context;
+mod dyn_reporter;
+mod envelope;
 mod handler;
+#[cfg(any(feature = "std_channel", feature = "tokio"))]
+mod handler_panic;
+mod handlers;
+mod inline_reporter;
 mod listener;
+mod map;
 mod reporter;
+mod span;
 #[cfg(test)]
 mod tests;
 
-#[cfg(feature = "channel_reporter")]
+#[cfg(feature = "std_channel")]
 mod channel_reporter;
 
-#[cfg(feature = "channel_reporter")]
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "tokio")]
+mod tokio_reporter;
+
+#[cfg(feature = "std_channel")]
 pub use channel_reporter::{
-    channel::event_channel, channel::EventReceiver, channel::EventSendError, channel::EventSender,
-    listener::ChannelEventListener, listener::ChannelHandlerGuard, reporter::ChannelReporter,
-    reporter::DisconnectToken, reporter::EventReporterError,
+    channel::bounded_event_channel, channel::event_channel, channel::BackpressurePolicy,
+    channel::EventReceiver, channel::EventSendError, channel::EventSender,
+    listener::ChannelEventListener, listener::ChannelHandlerGuard, listener::JoinError,
+    listener::JoinTimeoutError, listener::JoinWithStatsError, reporter::ChannelReporter,
+    reporter::EventReporterError, reporter::FlushError, reporter::ReporterHandle, scope::scope,
+    stats::HandlerStats,
+};
+#[cfg(feature = "tokio")]
+pub use tokio_reporter::{
+    channel::tokio_event_channel, channel::TokioEventReceiver, channel::TokioEventSender,
+    listener::TokioEventListener, listener::TokioHandlerGuard, listener::TokioJoinError,
+    reporter::TokioReporter, reporter::TokioReporterError,
+};
+
+#[cfg(feature = "async")]
+pub use async_handler::{AsyncEventHandler, SyncHandlerAdapter};
+pub use context::{ContextReporter, MergeContext, WithContext};
+pub use dyn_reporter::{DynReporter, DynReporterError};
+pub use envelope::Envelope;
+pub use handler::{ErrorPolicy, EventHandler, EventHandlerExt, FallibleEventHandler};
+#[cfg(any(feature = "std_channel", feature = "tokio"))]
+pub use handler_panic::HandlerPanic;
+pub use handlers::combinators::{Filter, FilterMap, Inspect, Map};
+pub use handlers::envelope::{RenderEnvelope, UnwrapEnvelope};
+pub use handlers::fan_out::FanOutHandler;
+pub use handlers::handler_fn::{
+    handler_fn, handler_fn_with_finish, HandlerFn, HandlerFnWithFinish,
+};
+#[cfg(feature = "json")]
+pub use handlers::json_lines::{JsonErrorPolicy, JsonLinesError, JsonLinesHandler};
+#[cfg(feature = "indicatif")]
+pub use handlers::progress::{ProgressHandler, ProgressMapping, ProgressUpdate};
+pub use handlers::router::RouterHandler;
+pub use inline_reporter::{
+    inline_pair, listener::InlineEventListener, listener::InlineHandlerGuard,
+    reporter::InlineReporter, reporter::InlineReporterError,
 };
-pub use handler::EventHandler;
+#[cfg(feature = "async")]
+pub use listener::AsyncEventListener;
 pub use listener::{EventListener, HandlerGuard};
-pub use reporter::EventReporter;
+pub use map::MapReporter;
+#[cfg(any(feature = "std_channel", feature = "tokio"))]
+pub use reporter::DisconnectToken;
+pub use reporter::{EventReporter, EventReporterExt};
+pub use span::{SpanEvent, SpanGuard, SpanId};
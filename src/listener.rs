@@ -1,3 +1,5 @@
+#[cfg(feature = "async")]
+use crate::AsyncEventHandler;
 use crate::EventHandler;
 use std::sync::Arc;
 
@@ -6,8 +8,8 @@ use std::sync::Arc;
 ///
 /// You may use the included [`ChannelEventListener`] in combination with the
 /// included [`ChannelReporter`], to create a reporter and listener which use a
-/// crossbeam channel to communicate. This assumes the `channel_reporter` feature is enabled
-/// (default).
+/// channel to communicate. This assumes the `channel_reporter` feature (default), or the
+/// `std_channel` feature is enabled.
 ///
 /// The listener should not block (you can, for example, spawn a thread to which you can communicate
 /// using a channel).
@@ -28,6 +30,27 @@ pub trait EventListener {
         H: EventHandler<Event = Self::Event> + 'static;
 }
 
+/// A listener, which listens to events from a [`EventReporter`],
+/// and can act upon these events by using an [`AsyncEventHandler`].
+///
+/// Requires the `async` feature. The `TokioEventListener` (behind the `tokio` feature)
+/// implements this trait.
+///
+/// [`EventReporter`]: crate::EventReporter
+/// [`AsyncEventHandler`]: crate::AsyncEventHandler
+#[cfg(feature = "async")]
+pub trait AsyncEventListener {
+    /// The type of message send from a reporter to some listener.
+    type Event;
+
+    /// A guard that keeps the handler running and can be used to wait for it to finish.
+    type Guard: HandlerGuard;
+
+    fn run_async_handler<H>(&self, handler: Arc<H>) -> Self::Guard
+    where
+        H: AsyncEventHandler<Event = Self::Event> + 'static;
+}
+
 /// A guard over a running [`EventHandler`].
 ///
 /// Returned by [`EventListener::run_handler`] and held until the caller is ready to wait
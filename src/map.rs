@@ -0,0 +1,77 @@
+//! Reporters which map events to the event type of another reporter.
+
+use crate::EventReporter;
+use std::marker::PhantomData;
+
+/// A reporter for events of type `E`, which maps each event to the event type of the reporter it
+/// was created from, and reports it there.
+///
+/// Created by [`EventReporterExt::map`]. This allows a library to report its own event type,
+/// while the application reports the events of the library as part of its own event type. The
+/// library only needs to depend on the [`EventReporter`] trait.
+///
+/// ```
+/// # #[cfg(feature = "std_channel")]
+/// # {
+/// use storyteller::{event_channel, ChannelReporter, EventReporter, EventReporterExt};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct LibEvent(u32);
+///
+/// #[derive(Debug, PartialEq)]
+/// enum AppEvent {
+///     Lib(LibEvent),
+/// }
+///
+/// // Library code, which knows nothing about `AppEvent`
+/// fn library(reporter: &impl EventReporter<Event = LibEvent>) {
+///     let _ = reporter.report_event(LibEvent(1));
+/// }
+///
+/// let (sender, receiver) = event_channel::<AppEvent>();
+/// let reporter = ChannelReporter::new(sender);
+///
+/// library(&reporter.map(AppEvent::Lib));
+///
+/// assert_eq!(receiver.recv().unwrap(), AppEvent::Lib(LibEvent(1)));
+/// # }
+/// ```
+///
+/// Disconnecting a `MapReporter` does nothing: the reporter it was created from needs to be
+/// disconnected.
+///
+/// [`EventReporterExt::map`]: crate::EventReporterExt::map
+/// [`EventReporter`]: crate::EventReporter
+pub struct MapReporter<'reporter, R, E, F> {
+    reporter: &'reporter R,
+    map: F,
+    _event: PhantomData<fn(E)>,
+}
+
+impl<'reporter, R, E, F> MapReporter<'reporter, R, E, F> {
+    pub(crate) fn new(reporter: &'reporter R, map: F) -> Self {
+        Self {
+            reporter,
+            map,
+            _event: PhantomData,
+        }
+    }
+}
+
+impl<'reporter, R, E, F> EventReporter for MapReporter<'reporter, R, E, F>
+where
+    R: EventReporter,
+    F: Fn(E) -> R::Event,
+{
+    type Event = E;
+    type Err = R::Err;
+    type DisconnectToken = ();
+
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        self.reporter.report_event((self.map)(event.into()))
+    }
+
+    fn disconnect(self) -> Result<Self::DisconnectToken, Self::Err> {
+        Ok(())
+    }
+}
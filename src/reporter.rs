@@ -1,3 +1,5 @@
+use crate::{ContextReporter, MapReporter, SpanEvent, SpanGuard};
+
 /// A reporter (a type of transmitter) which sends events (the message to be transmitted) to
 /// a listener (a type of receiver).
 pub trait EventReporter {
@@ -35,3 +37,101 @@ pub trait EventReporter {
     /// [`HandlerGuard::join`]: crate::HandlerGuard::join
     fn disconnect(self) -> Result<Self::DisconnectToken, Self::Err>;
 }
+
+/// Additional reporting methods, which are available for every [`EventReporter`].
+///
+/// [`EventReporter`]: crate::EventReporter
+pub trait EventReporterExt: EventReporter {
+    /// Start a span: an activity which may contain other spans, and which ends when the
+    /// returned guard is dropped.
+    ///
+    /// Reports a [`SpanEvent::Start`] event now, and a [`SpanEvent::End`] event, with the
+    /// duration of the span, when the span ends. Spans within this span can be started with
+    /// [`SpanGuard::child`]; their start events refer to this span as their parent.
+    ///
+    /// ```
+    /// # #[cfg(feature = "std_channel")]
+    /// # {
+    /// use storyteller::{event_channel, ChannelReporter, EventReporterExt, SpanEvent};
+    ///
+    /// let (sender, receiver) = event_channel::<SpanEvent<String>>();
+    /// let reporter = ChannelReporter::new(sender);
+    ///
+    /// {
+    ///     let resolve = reporter.span("resolve", "my-package".to_string()).unwrap();
+    ///     let _download = resolve.child("download", "dependency".to_string()).unwrap();
+    /// }
+    ///
+    /// let resolve = receiver.recv().unwrap().id();
+    ///
+    /// match receiver.recv().unwrap() {
+    ///     SpanEvent::Start { name, parent, .. } => {
+    ///         assert_eq!(name, "download");
+    ///         assert_eq!(parent, Some(resolve));
+    ///     }
+    ///     SpanEvent::End { .. } => unreachable!(),
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`SpanEvent::Start`]: crate::SpanEvent::Start
+    /// [`SpanEvent::End`]: crate::SpanEvent::End
+    /// [`SpanGuard::child`]: crate::SpanGuard::child
+    fn span<P>(&self, name: &'static str, payload: P) -> Result<SpanGuard<'_, Self, P>, Self::Err>
+    where
+        Self: Sized,
+        SpanEvent<P>: Into<Self::Event>,
+    {
+        SpanGuard::start(self, None, name, payload)
+    }
+
+    /// Create a reporter which merges the `context` into every event, before reporting it with
+    /// this reporter.
+    ///
+    /// See [`ContextReporter`].
+    ///
+    /// [`ContextReporter`]: crate::ContextReporter
+    fn with_context<C>(&self, context: C) -> ContextReporter<'_, Self, C>
+    where
+        Self: Sized,
+    {
+        ContextReporter::new(self, context)
+    }
+
+    /// Create a reporter for events of type `E`, which maps each event with `map`, before
+    /// reporting it with this reporter.
+    ///
+    /// See [`MapReporter`].
+    ///
+    /// [`MapReporter`]: crate::MapReporter
+    fn map<E, F>(&self, map: F) -> MapReporter<'_, Self, E, F>
+    where
+        Self: Sized,
+        F: Fn(E) -> Self::Event,
+    {
+        MapReporter::new(self, map)
+    }
+}
+
+impl<R: EventReporter> EventReporterExt for R {}
+
+/// Proof that a `ChannelReporter` or a `TokioReporter` has been disconnected.
+///
+/// Produced by [`EventReporter::disconnect`] of these reporters, and consumed by
+/// [`HandlerGuard::join`] of their guards. Because the inner field is private and this type
+/// has no public constructor, the only way to obtain a `DisconnectToken` value is by
+/// disconnecting a reporter, which should guarantee correct ordering.
+///
+/// Requires the `std_channel` or the `tokio` feature.
+///
+/// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+/// [`HandlerGuard::join`]: crate::HandlerGuard::join
+#[cfg(any(feature = "std_channel", feature = "tokio"))]
+pub struct DisconnectToken(());
+
+#[cfg(any(feature = "std_channel", feature = "tokio"))]
+impl DisconnectToken {
+    pub(crate) fn new() -> Self {
+        Self(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Spans: nested activities, reported as start and end events.
+
+use crate::EventReporter;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// The identifier of the next span, shared by all spans in the process.
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies a span. Unique within the process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpanId(u64);
+
+impl SpanId {
+    fn next() -> Self {
+        Self(NEXT_SPAN_ID.fetch_add(1, Ordering::SeqCst))
+    }
+
+    /// The identifier as a number, for example to refer to the span in serialized output.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+/// The events reported for a span, by [`EventReporterExt::span`] and [`SpanGuard`].
+///
+/// A reporter can report span events when its event type can be created from a `SpanEvent`.
+/// This is either the `SpanEvent` itself, or an event type which implements
+/// `From<SpanEvent<P>>`, for example with a variant which holds the span event.
+///
+/// [`EventReporterExt::span`]: crate::EventReporterExt::span
+/// [`SpanGuard`]: crate::SpanGuard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpanEvent<P> {
+    /// A span was started.
+    Start {
+        /// The identifier of the span.
+        id: SpanId,
+        /// The identifier of the span this span was started in, if any.
+        parent: Option<SpanId>,
+        /// The name of the span, for example "download".
+        name: &'static str,
+        /// Describes the activity of the span, for example the crate being downloaded.
+        payload: P,
+    },
+    /// A span was ended.
+    End {
+        /// The identifier of the span.
+        id: SpanId,
+        /// The time between starting and ending the span.
+        duration: Duration,
+    },
+}
+
+impl<P> SpanEvent<P> {
+    /// The identifier of the span this event belongs to.
+    pub fn id(&self) -> SpanId {
+        match self {
+            Self::Start { id, .. } | Self::End { id, .. } => *id,
+        }
+    }
+}
+
+/// An active span, created by [`EventReporterExt::span`] or [`SpanGuard::child`].
+///
+/// The span ends when the guard is dropped, or when [`SpanGuard::end`] is called. Ending the
+/// span reports a [`SpanEvent::End`] event, with the duration of the span.
+///
+/// [`EventReporterExt::span`]: crate::EventReporterExt::span
+/// [`SpanGuard::child`]: crate::SpanGuard::child
+/// [`SpanGuard::end`]: crate::SpanGuard::end
+/// [`SpanEvent::End`]: crate::SpanEvent::End
+#[must_use = "the span ends when the guard is dropped"]
+pub struct SpanGuard<'reporter, R, P>
+where
+    R: EventReporter,
+    SpanEvent<P>: Into<R::Event>,
+{
+    reporter: &'reporter R,
+    id: SpanId,
+    started: Instant,
+    ended: bool,
+    _payload: PhantomData<fn(P)>,
+}
+
+impl<'reporter, R, P> SpanGuard<'reporter, R, P>
+where
+    R: EventReporter,
+    SpanEvent<P>: Into<R::Event>,
+{
+    pub(crate) fn start(
+        reporter: &'reporter R,
+        parent: Option<SpanId>,
+        name: &'static str,
+        payload: P,
+    ) -> Result<Self, R::Err> {
+        let id = SpanId::next();
+
+        reporter.report_event(SpanEvent::Start {
+            id,
+            parent,
+            name,
+            payload,
+        })?;
+
+        Ok(Self {
+            reporter,
+            id,
+            started: Instant::now(),
+            ended: false,
+            _payload: PhantomData,
+        })
+    }
+
+    /// The identifier of this span.
+    pub fn id(&self) -> SpanId {
+        self.id
+    }
+
+    /// Start a span within this span.
+    ///
+    /// The payload of the child may be of another type than the payload of this span, as long
+    /// as the event type of the reporter can be created from a `SpanEvent` of either.
+    pub fn child<Q>(
+        &self,
+        name: &'static str,
+        payload: Q,
+    ) -> Result<SpanGuard<'reporter, R, Q>, R::Err>
+    where
+        SpanEvent<Q>: Into<R::Event>,
+    {
+        SpanGuard::start(self.reporter, Some(self.id), name, payload)
+    }
+
+    /// End the span, and report whether the end event could be reported.
+    ///
+    /// Dropping the guard ends the span as well, but ignores a failure to report.
+    pub fn end(mut self) -> Result<(), R::Err> {
+        self.ended = true;
+        self.report_end()
+    }
+
+    fn report_end(&self) -> Result<(), R::Err> {
+        self.reporter.report_event(SpanEvent::<P>::End {
+            id: self.id,
+            duration: self.started.elapsed(),
+        })
+    }
+}
+
+impl<'reporter, R, P> Drop for SpanGuard<'reporter, R, P>
+where
+    R: EventReporter,
+    SpanEvent<P>: Into<R::Event>,
+{
+    fn drop(&mut self) {
+        if !self.ended {
+            let _ = self.report_end();
+        }
+    }
+}
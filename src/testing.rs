@@ -0,0 +1,124 @@
+//! Utilities to test flows which report events.
+//!
+//! Requires the `testing` feature.
+
+use crate::EventHandler;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+/// A handler which records the events it receives, and whether it was finished, so they
+/// can be asserted on after the handler has been joined.
+///
+/// ### Ordering
+///
+/// The events are handled by the [`EventListener`], so only assert on them after the
+/// [`HandlerGuard`] has been joined, to be sure all events have been recorded.
+///
+/// [`EventListener`]: crate::EventListener
+/// [`HandlerGuard`]: crate::HandlerGuard
+pub struct RecordingHandler<Event> {
+    events: Mutex<Vec<Event>>,
+    finished: AtomicUsize,
+}
+
+impl<Event> RecordingHandler<Event> {
+    /// Create a handler which has not recorded any events yet.
+    pub fn new() -> Self {
+        Self {
+            events: Mutex::new(Vec::new()),
+            finished: AtomicUsize::new(0),
+        }
+    }
+
+    /// A copy of the events recorded so far, in the order in which they were received.
+    pub fn events(&self) -> Vec<Event>
+    where
+        Event: Clone,
+    {
+        self.lock().clone()
+    }
+
+    /// The number of events recorded so far.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no events have been recorded so far.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Whether [`EventHandler::finish`] has been called.
+    ///
+    /// [`EventHandler::finish`]: crate::EventHandler::finish
+    pub fn is_finished(&self) -> bool {
+        self.finish_count() > 0
+    }
+
+    /// The number of times [`EventHandler::finish`] has been called.
+    ///
+    /// [`EventHandler::finish`]: crate::EventHandler::finish
+    pub fn finish_count(&self) -> usize {
+        self.finished.load(Ordering::SeqCst)
+    }
+
+    /// Assert that exactly the `expected` events were recorded, in this order.
+    #[track_caller]
+    pub fn assert_events_eq(&self, expected: &[Event])
+    where
+        Event: PartialEq + Debug,
+    {
+        assert_eq!(self.lock().as_slice(), expected);
+    }
+
+    /// Assert that the `expected` event has been recorded at least once.
+    #[track_caller]
+    pub fn assert_contains(&self, expected: &Event)
+    where
+        Event: PartialEq + Debug,
+    {
+        let events = self.lock();
+
+        assert!(
+            events.contains(expected),
+            "Expected event '{:?}' to be recorded, but only recorded: {:?}",
+            expected,
+            events.as_slice()
+        );
+    }
+
+    /// Assert that [`EventHandler::finish`] has been called.
+    ///
+    /// [`EventHandler::finish`]: crate::EventHandler::finish
+    #[track_caller]
+    pub fn assert_finished(&self) {
+        assert!(
+            self.is_finished(),
+            "Expected the handler to be finished, but `finish` was not called"
+        );
+    }
+
+    // A panicking handler should not prevent assertions on the events recorded before the panic.
+    fn lock(&self) -> MutexGuard<'_, Vec<Event>> {
+        self.events.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<Event> Default for RecordingHandler<Event> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Event: Send> EventHandler for RecordingHandler<Event> {
+    type Event = Event;
+
+    fn handle(&self, event: Self::Event) {
+        self.lock().push(event);
+    }
+
+    fn finish(&self) {
+        self.finished.fetch_add(1, Ordering::SeqCst);
+    }
+}
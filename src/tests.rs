@@ -1,4 +1,4 @@
-#![cfg(feature = "channel_reporter")]
+#![cfg(feature = "std_channel")]
 #![allow(unused_must_use)]
 
 // TODO: now we have a working proof of concept, and are starting to refine the library,
@@ -0,0 +1,56 @@
+//! Channels which can be used by the `TokioReporter` and `TokioEventListener`.
+
+use crate::TokioReporterError;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+/// A channel over which events are sent, from the `TokioReporter` to the `TokioEventListener`.
+///
+/// The channel is unbounded, so events can be sent from both synchronous and asynchronous code,
+/// without blocking.
+pub fn tokio_event_channel<Event>() -> (TokioEventSender<Event>, TokioEventReceiver<Event>) {
+    let (sender, receiver) = mpsc::unbounded_channel::<Event>();
+
+    (
+        TokioEventSender(sender),
+        TokioEventReceiver(Arc::new(Mutex::new(receiver))),
+    )
+}
+
+/// A sender, used by `TokioReporter` and `TokioEventListener`.
+pub struct TokioEventSender<T>(mpsc::UnboundedSender<T>);
+
+impl<T> TokioEventSender<T> {
+    pub fn send(&self, message: T) -> Result<(), TokioReporterError<T>> {
+        self.0
+            .send(message)
+            .map_err(|err| TokioReporterError::Disconnected(err.0))
+    }
+
+    /// When all senders are disconnected, the channel is disconnected
+    pub fn disconnect(self) {
+        drop(self.0)
+    }
+}
+
+/// A receiver, used by `TokioReporter` and `TokioEventListener`.
+///
+/// Like the [`EventReceiver`], it can be cloned, in which case each event is received by
+/// only one of the clones.
+///
+/// [`EventReceiver`]: crate::EventReceiver
+pub struct TokioEventReceiver<T>(Arc<Mutex<mpsc::UnboundedReceiver<T>>>);
+
+impl<T> TokioEventReceiver<T> {
+    /// Receive the next message, or `None` once the channel has been disconnected and
+    /// all queued messages have been received.
+    pub async fn recv(&self) -> Option<T> {
+        self.0.lock().await.recv().await
+    }
+}
+
+impl<T> Clone for TokioEventReceiver<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    AsyncEventHandler, AsyncEventListener, DisconnectToken, EventHandler, EventListener,
+    HandlerGuard, HandlerPanic, TokioEventReceiver,
+};
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::task::{Context, Poll};
+use std::{error, thread};
+use tokio::task::JoinHandle;
+
+/// A listener which uses a tokio channel to receive messages of type `Event`, and runs the
+/// event handler as a task on the current tokio runtime (in [`TokioEventListener::run_handler`]).
+///
+/// Both synchronous [`EventHandler`]s and [`AsyncEventHandler`]s are supported. The latter
+/// are run by [`AsyncEventListener::run_async_handler`].
+///
+/// The channel based receiver required to create an instance can be created by calling the
+/// [`tokio_event_channel()`] function.
+///
+/// The [`EventReporter`] associated with this event listener is the [`TokioReporter`].
+///
+/// [`TokioEventListener::run_handler`]: crate::TokioEventListener::run_handler
+/// [`EventHandler`]: crate::EventHandler
+/// [`AsyncEventHandler`]: crate::AsyncEventHandler
+/// [`AsyncEventListener::run_async_handler`]: crate::AsyncEventListener::run_async_handler
+/// [`tokio_event_channel()`]: crate::tokio_event_channel
+/// [`EventReporter`]: crate::EventReporter
+/// [`TokioReporter`]: crate::TokioReporter
+pub struct TokioEventListener<Event> {
+    event_receiver: TokioEventReceiver<Event>,
+}
+
+impl<Event> TokioEventListener<Event> {
+    /// Create a new tokio channel based event listener.
+    ///
+    /// The channel based receiver required to create an instance can be created by calling the
+    /// [`tokio_event_channel()`] function.
+    ///
+    /// [`tokio_event_channel()`]: crate::tokio_event_channel
+    pub fn new(event_receiver: TokioEventReceiver<Event>) -> Self {
+        Self { event_receiver }
+    }
+}
+
+impl<Event> EventListener for TokioEventListener<Event>
+where
+    Event: Send + 'static,
+{
+    type Event = Event;
+    type Guard = TokioHandlerGuard;
+
+    /// Spawn a task which runs the handler, on the current tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of the context of a tokio runtime.
+    fn run_handler<H>(&self, handler: Arc<H>) -> Self::Guard
+    where
+        H: EventHandler<Event = Self::Event> + 'static,
+    {
+        let event_receiver = self.event_receiver.clone();
+        let events_handled = Arc::new(AtomicUsize::new(0));
+        let counter = events_handled.clone();
+
+        let handler_loop = async move {
+            while let Some(message) = event_receiver.recv().await {
+                handler.handle(message);
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+
+            handler.finish();
+        };
+
+        spawn_handler(handler_loop, events_handled)
+    }
+}
+
+impl<Event> AsyncEventListener for TokioEventListener<Event>
+where
+    Event: Send + 'static,
+{
+    type Event = Event;
+    type Guard = TokioHandlerGuard;
+
+    /// Spawn a task which runs the asynchronous handler, on the current tokio runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of the context of a tokio runtime.
+    fn run_async_handler<H>(&self, handler: Arc<H>) -> Self::Guard
+    where
+        H: AsyncEventHandler<Event = Self::Event> + 'static,
+    {
+        let event_receiver = self.event_receiver.clone();
+        let events_handled = Arc::new(AtomicUsize::new(0));
+        let counter = events_handled.clone();
+
+        let handler_loop = async move {
+            while let Some(message) = event_receiver.recv().await {
+                handler.handle(message).await;
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+
+            handler.finish().await;
+        };
+
+        spawn_handler(handler_loop, events_handled)
+    }
+}
+
+fn spawn_handler<F>(handler_loop: F, events_handled: Arc<AtomicUsize>) -> TokioHandlerGuard
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let (done_sender, done_receiver) = mpsc::sync_channel(1);
+
+    let handle = tokio::spawn(async move {
+        let outcome = CatchUnwind(Box::pin(handler_loop)).await;
+
+        // Not sent when the task was cancelled, in which case the sender is dropped instead
+        let _ = done_sender.send(outcome);
+    });
+
+    TokioHandlerGuard::new(handle, done_receiver, events_handled)
+}
+
+/// Catches a panic of the handler, so the panic payload can be handed to the guard, regardless
+/// of whether the guard is joined asynchronously or by blocking.
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let handler_loop = self.0.as_mut();
+
+        match panic::catch_unwind(AssertUnwindSafe(|| handler_loop.poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
+
+/// A [`HandlerGuard`] for the [`TokioEventListener`].
+///
+/// Holds the handler task and allows waiting for it to finish, either asynchronously via
+/// [`TokioHandlerGuard::join`], or by blocking the current thread via [`HandlerGuard::join`].
+///
+/// ### Ordering
+///
+/// Both ways of joining require a [`DisconnectToken`] proof token produced by
+/// [`EventReporter::disconnect`] of the [`TokioReporter`], just like the
+/// [`ChannelHandlerGuard`].
+///
+/// ### Drop behaviour
+///
+/// Dropping this guard without joining it is a programming error and will panic
+/// (unless the thread is already unwinding).
+///
+/// [`HandlerGuard`]: crate::HandlerGuard
+/// [`TokioEventListener`]: crate::TokioEventListener
+/// [`TokioHandlerGuard::join`]: crate::TokioHandlerGuard::join
+/// [`HandlerGuard::join`]: crate::HandlerGuard::join
+/// [`DisconnectToken`]: crate::DisconnectToken
+/// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+/// [`TokioReporter`]: crate::TokioReporter
+/// [`ChannelHandlerGuard`]: crate::ChannelHandlerGuard
+#[must_use]
+pub struct TokioHandlerGuard {
+    handle: Option<JoinHandle<()>>,
+    done: mpsc::Receiver<thread::Result<()>>,
+    events_handled: Arc<AtomicUsize>,
+}
+
+impl TokioHandlerGuard {
+    fn new(
+        handle: JoinHandle<()>,
+        done: mpsc::Receiver<thread::Result<()>>,
+        events_handled: Arc<AtomicUsize>,
+    ) -> Self {
+        Self {
+            handle: Some(handle),
+            done,
+            events_handled,
+        }
+    }
+
+    /// Wait for the handler task to finish processing all queued events.
+    ///
+    /// `token` must be the [`DisconnectToken`] value produced by [`EventReporter::disconnect`].
+    ///
+    /// Resolves to a [`TokioJoinError::Panicked`] if the handler panicked, or to a
+    /// [`TokioJoinError::Cancelled`] if the handler task was cancelled.
+    ///
+    /// [`DisconnectToken`]: crate::DisconnectToken
+    /// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+    /// [`TokioJoinError::Panicked`]: crate::TokioJoinError::Panicked
+    /// [`TokioJoinError::Cancelled`]: crate::TokioJoinError::Cancelled
+    pub async fn join(mut self, _token: DisconnectToken) -> Result<(), TokioJoinError> {
+        // The outcome is sent before the task finishes, unless the task was cancelled
+        let _ = self.handle.take().unwrap().await;
+        let outcome = self.done.try_recv().ok();
+
+        self.join_result(outcome)
+    }
+
+    fn join_result(&self, outcome: Option<thread::Result<()>>) -> Result<(), TokioJoinError> {
+        match outcome {
+            Some(Ok(())) => Ok(()),
+            Some(Err(payload)) => Err(TokioJoinError::Panicked(HandlerPanic::new(
+                payload,
+                self.events_handled.load(Ordering::SeqCst),
+            ))),
+            None => Err(TokioJoinError::Cancelled),
+        }
+    }
+}
+
+impl HandlerGuard for TokioHandlerGuard {
+    type Err = TokioJoinError;
+    type Token = DisconnectToken;
+
+    /// Block the current thread until the handler task has finished processing all queued events.
+    ///
+    /// Prefer [`TokioHandlerGuard::join`] from asynchronous code. Blocking a thread of the
+    /// runtime on which the handler task runs, may prevent the handler task from making
+    /// progress, for example on a `current_thread` runtime.
+    ///
+    /// [`TokioHandlerGuard::join`]: crate::TokioHandlerGuard::join
+    fn join(mut self, _token: DisconnectToken) -> Result<(), Self::Err> {
+        self.handle.take();
+        let outcome = self.done.recv().ok();
+
+        self.join_result(outcome)
+    }
+}
+
+impl Drop for TokioHandlerGuard {
+    fn drop(&mut self) {
+        if self.handle.is_some() && !thread::panicking() {
+            panic!(
+                "TokioHandlerGuard dropped without calling join(). \
+                 Call reporter.disconnect() then guard.join(token) before dropping"
+            );
+        }
+    }
+}
+
+/// The reason why joining a [`TokioHandlerGuard`] failed.
+///
+/// [`TokioHandlerGuard`]: crate::TokioHandlerGuard
+#[derive(Debug, PartialEq, Eq)]
+pub enum TokioJoinError {
+    /// The handler task panicked.
+    Panicked(HandlerPanic),
+    /// The handler task was cancelled before it finished, because its tokio runtime shut down.
+    Cancelled,
+}
+
+impl Display for TokioJoinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Panicked(panic) => Display::fmt(panic, f),
+            Self::Cancelled => f.write_str("handler task was cancelled"),
+        }
+    }
+}
+
+impl error::Error for TokioJoinError {}
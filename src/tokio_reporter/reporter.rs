@@ -0,0 +1,102 @@
+use crate::{DisconnectToken, EventReporter, TokioEventSender};
+use std::error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// A reporter which sends events over a tokio channel, to a [`TokioEventListener`].
+///
+/// Reporting an event never blocks, so the reporter can be used from both synchronous and
+/// asynchronous code.
+///
+/// Use [`EventReporter::disconnect`] to disconnect the channel by dropping the `sender`.
+/// This returns a [`DisconnectToken`] proof token which must be passed to
+/// [`TokioHandlerGuard::join`] to wait for the handler task to drain and exit.
+///
+/// The channel sender (and channel receiver for the `listener`), required to create a
+/// `TokioReporter` instance can be created by calling the [`tokio_event_channel()`]
+/// function.
+///
+/// [`TokioEventListener`]: crate::TokioEventListener
+/// [`EventReporter::disconnect`]: crate::EventReporter::disconnect
+/// [`DisconnectToken`]: crate::DisconnectToken
+/// [`TokioHandlerGuard::join`]: crate::TokioHandlerGuard::join
+/// [`tokio_event_channel()`]: crate::tokio_event_channel()
+pub struct TokioReporter<Event> {
+    event_sender: TokioEventSender<Event>,
+}
+
+impl<Event> TokioReporter<Event> {
+    /// Setup a reporter which uses a tokio channel.
+    ///
+    /// The channel required to create an instance can be created by calling the
+    /// [`crate::tokio_event_channel`] function.
+    pub fn new(event_sender: TokioEventSender<Event>) -> Self {
+        Self { event_sender }
+    }
+}
+
+impl<Event> EventReporter for TokioReporter<Event> {
+    type Event = Event;
+    type Err = TokioReporterError<Event>;
+    type DisconnectToken = DisconnectToken;
+
+    fn report_event(&self, event: impl Into<Self::Event>) -> Result<(), Self::Err> {
+        self.event_sender.send(event.into())
+    }
+
+    /// Disconnect the sender, returning a [`DisconnectToken`] token.
+    ///
+    /// Pass the token to [`TokioHandlerGuard::join`] to wait for the handler task to finish
+    /// draining the queue. The token enforces that this call happens first.
+    ///
+    /// [`TokioHandlerGuard::join`]: crate::TokioHandlerGuard::join
+    fn disconnect(self) -> Result<DisconnectToken, Self::Err> {
+        self.event_sender.disconnect();
+        Ok(DisconnectToken::new())
+    }
+}
+
+/// The reason why an event could not be reported by the [`TokioReporter`]. The event is
+/// handed back.
+///
+/// [`TokioReporter`]: crate::TokioReporter
+pub enum TokioReporterError<Event> {
+    /// The receiver of the channel was dropped, so the event can no longer be handled.
+    Disconnected(Event),
+}
+
+impl<Event> TokioReporterError<Event> {
+    /// Take back the event which could not be reported.
+    pub fn into_event(self) -> Event {
+        match self {
+            Self::Disconnected(event) => event,
+        }
+    }
+}
+
+impl<Event> Debug for TokioReporterError<Event> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disconnected(_) => f.write_fmt(format_args!(
+                "Disconnected({})",
+                std::any::type_name::<Event>()
+            )),
+        }
+    }
+}
+
+impl<Event> Display for TokioReporterError<Event>
+where
+    Event: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disconnected(ev) => f.write_fmt(format_args!(
+                "Disconnected({} = '{}')",
+                std::any::type_name::<Event>(),
+                ev
+            )),
+        }
+    }
+}
+
+impl<Event> error::Error for TokioReporterError<Event> where Event: Display {}
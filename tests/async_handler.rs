@@ -0,0 +1,89 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use storyteller::{
+    tokio_event_channel, AsyncEventHandler, AsyncEventListener, EventHandler, EventReporter,
+    SyncHandlerAdapter, TokioEventListener, TokioReporter,
+};
+use tokio::sync::Mutex;
+
+struct SlowRegisteringHandler {
+    registered_events: Mutex<Vec<usize>>,
+    finished: AtomicBool,
+}
+
+impl SlowRegisteringHandler {
+    fn new() -> Self {
+        Self {
+            registered_events: Mutex::new(Vec::new()),
+            finished: AtomicBool::new(false),
+        }
+    }
+}
+
+impl AsyncEventHandler for SlowRegisteringHandler {
+    type Event = usize;
+
+    async fn handle(&self, event: Self::Event) {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        self.registered_events.lock().await.push(event);
+    }
+
+    async fn finish(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn run_async_handler() {
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let handler = Arc::new(SlowRegisteringHandler::new());
+    let guard = listener.run_async_handler(handler.clone());
+
+    for i in 0usize..5 {
+        reporter.report_event(i).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).await.unwrap();
+
+    assert_eq!(*handler.registered_events.lock().await, vec![0, 1, 2, 3, 4]);
+    assert!(handler.finished.load(Ordering::SeqCst));
+}
+
+struct FinishFlagHandler {
+    finished: AtomicBool,
+}
+
+impl EventHandler for FinishFlagHandler {
+    type Event = usize;
+
+    fn handle(&self, _: Self::Event) {}
+
+    fn finish(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test]
+async fn run_sync_handler_as_async_handler() {
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let handler = Arc::new(SyncHandlerAdapter::new(FinishFlagHandler {
+        finished: AtomicBool::new(false),
+    }));
+    let guard = listener.run_async_handler(handler.clone());
+
+    reporter.report_event(0usize).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).await.unwrap();
+
+    let handler = Arc::try_unwrap(handler).ok().unwrap().into_inner();
+    assert!(handler.finished.load(Ordering::SeqCst));
+}
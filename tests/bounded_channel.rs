@@ -0,0 +1,179 @@
+use std::sync::Arc;
+use std::time::Duration;
+use storyteller::testing::RecordingHandler;
+use storyteller::{
+    bounded_event_channel, BackpressurePolicy, ChannelEventListener, ChannelReporter,
+    EventListener, EventReporter, EventReporterError, HandlerGuard,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MyEvent(usize);
+
+// Fills the channel before the handler runs, so the outcome of the policy is deterministic.
+fn report_before_handling(policy: BackpressurePolicy) -> (Vec<bool>, Vec<MyEvent>) {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(2, policy);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let reported = (0..4)
+        .map(|i| reporter.report_event(MyEvent(i)).is_ok())
+        .collect();
+
+    let handler = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    (reported, handler.events())
+}
+
+#[test]
+fn drop_newest() {
+    let (reported, events) = report_before_handling(BackpressurePolicy::DropNewest);
+
+    assert_eq!(reported, vec![true, true, true, true]);
+    assert_eq!(events, vec![MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+fn drop_oldest() {
+    let (reported, events) = report_before_handling(BackpressurePolicy::DropOldest);
+
+    assert_eq!(reported, vec![true, true, true, true]);
+    assert_eq!(events, vec![MyEvent(2), MyEvent(3)]);
+}
+
+#[test]
+fn fail() {
+    let (reported, events) = report_before_handling(BackpressurePolicy::Fail);
+
+    assert_eq!(reported, vec![true, true, false, false]);
+    assert_eq!(events, vec![MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+fn drop_oldest_without_capacity_drops_newest() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(0, BackpressurePolicy::DropOldest);
+    let reporter = ChannelReporter::new(sender);
+
+    // No listener is waiting, so there is no room for the event, and no older event to discard.
+    reporter.report_event(MyEvent(0)).unwrap();
+    reporter.report_event(MyEvent(1)).unwrap();
+
+    assert!(receiver.is_empty());
+
+    let _token = reporter.disconnect().unwrap();
+    assert!(receiver.recv().is_err());
+}
+
+#[test]
+fn fail_returns_rejected_event() {
+    let (sender, _receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Fail);
+    let reporter = ChannelReporter::new(sender);
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    let err = reporter.report_event(MyEvent(1)).unwrap_err();
+
+    assert!(matches!(err, EventReporterError::Rejected(MyEvent(1))));
+}
+
+#[test]
+fn block_waits_for_handler() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let handler = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    for i in 0..10 {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    assert_eq!(handler.events(), (0..10).map(MyEvent).collect::<Vec<_>>());
+}
+
+#[test]
+fn try_report_event_when_full() {
+    let (sender, _receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+
+    reporter.try_report_event(MyEvent(0)).unwrap();
+    let err = reporter.try_report_event(MyEvent(1)).unwrap_err();
+
+    assert!(matches!(err, EventReporterError::Full(MyEvent(1))));
+}
+
+#[test]
+fn report_event_timeout_when_full() {
+    let (sender, _receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+
+    reporter
+        .report_event_timeout(MyEvent(0), Duration::from_millis(10))
+        .unwrap();
+    let err = reporter
+        .report_event_timeout(MyEvent(1), Duration::from_millis(10))
+        .unwrap_err();
+
+    assert_eq!(err.into_event(), MyEvent(1));
+}
+
+#[test]
+fn report_event_timeout_without_deadline() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    reporter.report_event(MyEvent(0)).unwrap();
+
+    // The channel is full until the handler runs, and the timeout is too large for a deadline.
+    let handler = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    reporter
+        .report_event_timeout(MyEvent(1), Duration::MAX)
+        .unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    handler.assert_events_eq(&[MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+fn try_report_event_on_dropped_receiver() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    let err = reporter.try_report_event(MyEvent(0)).unwrap_err();
+
+    assert!(matches!(err, EventReporterError::Disconnected(MyEvent(0))));
+}
+
+#[yare::parameterized(
+    drop_newest = { BackpressurePolicy::DropNewest },
+    drop_oldest = { BackpressurePolicy::DropOldest },
+    fail = { BackpressurePolicy::Fail },
+)]
+fn receiver_len_counts_queued_events(policy: BackpressurePolicy) {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(2, policy);
+    let reporter = ChannelReporter::new(sender);
+
+    for i in 0..4 {
+        let _ = reporter.report_event(MyEvent(i));
+    }
+
+    assert_eq!(receiver.len(), 2);
+
+    receiver.recv().unwrap();
+    assert_eq!(receiver.len(), 1);
+
+    receiver.recv().unwrap();
+    assert!(receiver.is_empty());
+}
@@ -0,0 +1,107 @@
+use storyteller::{
+    event_channel, ChannelReporter, EventReporter, EventReporterExt, MergeContext, SpanEvent,
+    WithContext,
+};
+
+#[derive(Debug, Default, PartialEq)]
+struct MyEvent {
+    message: &'static str,
+    member: Option<&'static str>,
+    target: Option<&'static str>,
+}
+
+impl From<&'static str> for MyEvent {
+    fn from(message: &'static str) -> Self {
+        Self {
+            message,
+            ..Self::default()
+        }
+    }
+}
+
+struct Member(&'static str);
+struct Target(&'static str);
+
+impl MergeContext<Member> for MyEvent {
+    fn merge_context(&mut self, context: &Member) {
+        self.member = Some(context.0);
+    }
+}
+
+impl MergeContext<Target> for MyEvent {
+    fn merge_context(&mut self, context: &Target) {
+        self.target = Some(context.0);
+    }
+}
+
+#[test]
+fn merge_nested_contexts() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+
+    let member = reporter.with_context(Member("a"));
+    member.report_event("resolving").unwrap();
+
+    let target = member.with_context(Target("wasm32-unknown-unknown"));
+    target.report_event("compiling").unwrap();
+
+    reporter.report_event("done").unwrap();
+
+    assert_eq!(
+        receiver.recv().unwrap(),
+        MyEvent {
+            message: "resolving",
+            member: Some("a"),
+            target: None,
+        }
+    );
+    assert_eq!(
+        receiver.recv().unwrap(),
+        MyEvent {
+            message: "compiling",
+            member: Some("a"),
+            target: Some("wasm32-unknown-unknown"),
+        }
+    );
+    assert_eq!(receiver.recv().unwrap(), MyEvent::from("done"));
+}
+
+#[test]
+fn wrap_with_context() {
+    let (sender, receiver) = event_channel::<WithContext<String, &str>>();
+    let reporter = ChannelReporter::new(sender);
+
+    for member in ["a", "b"] {
+        let task = reporter.with_context(member.to_string());
+        task.with_context("build".to_string())
+            .report_event("compiling")
+            .unwrap();
+    }
+
+    let (event, contexts) = receiver.recv().unwrap().into_parts();
+    assert_eq!(event, "compiling");
+    assert_eq!(contexts, vec!["a".to_string(), "build".to_string()]);
+
+    let event = receiver.recv().unwrap();
+    assert_eq!(event.contexts(), &["b".to_string(), "build".to_string()]);
+}
+
+#[test]
+fn spans_within_context() {
+    let (sender, receiver) = event_channel::<WithContext<&str, SpanEvent<()>>>();
+    let reporter = ChannelReporter::new(sender);
+    let member = reporter.with_context("a");
+
+    member.span("build", ()).unwrap().end().unwrap();
+
+    let start = receiver.recv().unwrap();
+    assert!(matches!(
+        start.event(),
+        SpanEvent::Start { name: "build", .. }
+    ));
+    assert_eq!(start.contexts(), &["a"]);
+
+    let end = receiver.recv().unwrap();
+    assert!(matches!(end.event(), SpanEvent::End { .. }));
+    assert_eq!(end.contexts(), &["a"]);
+}
@@ -0,0 +1,93 @@
+use storyteller::{
+    event_channel, inline_pair, ChannelReporter, DynReporter, EventReporterError, EventReporterExt,
+    InlineReporterError,
+};
+
+#[derive(Debug, PartialEq)]
+enum AppEvent {
+    Plugin(&'static str),
+}
+
+impl From<&'static str> for AppEvent {
+    fn from(message: &'static str) -> Self {
+        Self::Plugin(message)
+    }
+}
+
+trait Plugin {
+    fn run(&self, reporter: &dyn DynReporter<&'static str>);
+}
+
+struct Greeter;
+
+impl Plugin for Greeter {
+    fn run(&self, reporter: &dyn DynReporter<&'static str>) {
+        reporter.report("hello").unwrap();
+    }
+}
+
+#[test]
+fn plugins_report_through_trait_object() {
+    let (sender, receiver) = event_channel::<AppEvent>();
+    let reporter = ChannelReporter::new(sender);
+
+    let plugins: Vec<Box<dyn Plugin>> = vec![Box::new(Greeter), Box::new(Greeter)];
+
+    for plugin in &plugins {
+        plugin.run(&reporter);
+    }
+
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Plugin("hello"));
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Plugin("hello"));
+}
+
+#[test]
+fn reporters_of_different_types() {
+    let (sender, receiver) = event_channel::<AppEvent>();
+    let channel = ChannelReporter::new(sender);
+    let mapped = channel.map(AppEvent::Plugin);
+
+    let reporters: Vec<&dyn DynReporter<&'static str>> = vec![&channel, &mapped];
+
+    for reporter in reporters {
+        reporter.report("hi").unwrap();
+    }
+
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Plugin("hi"));
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Plugin("hi"));
+}
+
+#[test]
+fn downcast_error() {
+    let (sender, receiver) = event_channel::<AppEvent>();
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    let reporter: &dyn DynReporter<&'static str> = &reporter;
+    let err = reporter.report("lost").unwrap_err();
+
+    assert!(err
+        .downcast_ref::<InlineReporterError<AppEvent>>()
+        .is_none());
+    assert_eq!(
+        err.to_string(),
+        format!("unable to report event: {:?}", err)
+    );
+
+    let err = err.downcast::<EventReporterError<AppEvent>>().unwrap();
+
+    assert_eq!(err.into_event(), AppEvent::Plugin("lost"));
+}
+
+#[test]
+fn inline_reporter_as_trait_object() {
+    let (reporter, _listener) = inline_pair::<AppEvent>();
+    let reporter: &dyn DynReporter<AppEvent> = &reporter;
+
+    // No handler is running
+    let err = reporter.report(AppEvent::Plugin("unhandled")).unwrap_err();
+
+    assert!(err
+        .downcast_ref::<InlineReporterError<AppEvent>>()
+        .is_some());
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::SystemTime;
+use storyteller::testing::RecordingHandler;
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, Envelope, EventHandler, EventListener,
+    EventReporter, HandlerGuard, RenderEnvelope, UnwrapEnvelope,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MyEvent(usize);
+
+#[test]
+fn reporter_fills_in_envelope() {
+    let (sender, receiver) = event_channel::<Envelope<MyEvent>>();
+    let reporter = ChannelReporter::new(sender);
+
+    let before = SystemTime::now();
+
+    thread::Builder::new()
+        .name("reporting".to_string())
+        .spawn(move || {
+            reporter.report_event(MyEvent(0)).unwrap();
+            reporter.report_event(MyEvent(1)).unwrap();
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let first = receiver.recv().unwrap();
+    let second = receiver.recv().unwrap();
+
+    assert!(first.sequence() < second.sequence());
+    assert!(first.instant() <= second.instant());
+    assert!(first.timestamp() >= before);
+    assert_eq!(first.thread_name(), Some("reporting"));
+    assert_eq!(first.event(), &MyEvent(0));
+    assert_eq!(second.into_event(), MyEvent(1));
+}
+
+#[test]
+fn sequence_increases_across_threads() {
+    let first = thread::spawn(|| Envelope::new(MyEvent(0))).join().unwrap();
+    let second = Envelope::new(MyEvent(1));
+
+    assert!(first.sequence() < second.sequence());
+}
+
+#[test]
+fn unwrap_envelope() {
+    let (sender, receiver) = event_channel::<Envelope<MyEvent>>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let recorder = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(Arc::new(UnwrapEnvelope::new(recorder.clone())));
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    reporter.report_event(MyEvent(1)).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    recorder.assert_events_eq(&[MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+fn render_envelope() {
+    let recorder = Arc::new(RecordingHandler::new());
+    let handler = RenderEnvelope::new(recorder.clone(), |envelope: Envelope<MyEvent>| {
+        format!(
+            "{} {}",
+            envelope.thread_name().unwrap_or("?"),
+            envelope.event().0
+        )
+    });
+
+    thread::Builder::new()
+        .name("renderer".to_string())
+        .spawn(move || handler.handle(Envelope::new(MyEvent(7))))
+        .unwrap()
+        .join()
+        .unwrap();
+
+    recorder.assert_events_eq(&["renderer 7".to_string()]);
+}
@@ -0,0 +1,112 @@
+use std::sync::{Arc, Mutex};
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, ErrorPolicy, EventReporter,
+    FallibleEventHandler, HandlerGuard, JoinError,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+enum WriteError {
+    Odd(usize),
+    Flush,
+}
+
+// Fails on odd events, and when finishing
+struct OddFailingHandler {
+    written: Mutex<Vec<usize>>,
+    fail_on_finish: bool,
+}
+
+impl OddFailingHandler {
+    fn new(fail_on_finish: bool) -> Self {
+        Self {
+            written: Mutex::new(Vec::new()),
+            fail_on_finish,
+        }
+    }
+
+    fn written(&self) -> Vec<usize> {
+        self.written.lock().unwrap().clone()
+    }
+}
+
+impl FallibleEventHandler for OddFailingHandler {
+    type Event = usize;
+    type Error = WriteError;
+
+    fn handle(&self, event: Self::Event) -> Result<(), Self::Error> {
+        if event % 2 == 1 {
+            return Err(WriteError::Odd(event));
+        }
+
+        self.written.lock().unwrap().push(event);
+        Ok(())
+    }
+
+    fn finish(&self) -> Result<(), Self::Error> {
+        if self.fail_on_finish {
+            Err(WriteError::Flush)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn run(
+    handler: Arc<OddFailingHandler>,
+    policy: ErrorPolicy,
+    events: &[usize],
+) -> Result<(), JoinError<WriteError>> {
+    let (sender, receiver) = event_channel::<usize>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let guard = listener.run_fallible_handler(handler, policy);
+
+    for event in events {
+        reporter.report_event(*event).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token)
+}
+
+#[test]
+fn stop_on_first_error() {
+    let handler = Arc::new(OddFailingHandler::new(true));
+    let result = run(
+        handler.clone(),
+        ErrorPolicy::StopOnFirstError,
+        &[0, 2, 3, 4, 5],
+    );
+
+    assert_eq!(result, Err(JoinError::Handler(vec![WriteError::Odd(3)])));
+    assert_eq!(handler.written(), vec![0, 2]);
+}
+
+#[test]
+fn collect() {
+    let handler = Arc::new(OddFailingHandler::new(true));
+    let result = run(handler.clone(), ErrorPolicy::Collect, &[0, 1, 2, 3, 4]);
+
+    assert_eq!(
+        result,
+        Err(JoinError::Handler(vec![
+            WriteError::Odd(1),
+            WriteError::Odd(3),
+            WriteError::Flush
+        ]))
+    );
+    assert_eq!(handler.written(), vec![0, 2, 4]);
+}
+
+#[yare::parameterized(
+    stop_on_first_error = { ErrorPolicy::StopOnFirstError },
+    collect = { ErrorPolicy::Collect },
+)]
+fn no_errors(policy: ErrorPolicy) {
+    let handler = Arc::new(OddFailingHandler::new(false));
+    let result = run(handler.clone(), policy, &[0, 2, 4]);
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(handler.written(), vec![0, 2, 4]);
+}
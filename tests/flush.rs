@@ -0,0 +1,238 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use storyteller::{
+    bounded_event_channel, event_channel, BackpressurePolicy, ChannelEventListener,
+    ChannelReporter, EventHandler, EventListener, EventReporter, FlushError, HandlerGuard,
+    JoinError,
+};
+
+#[derive(Debug)]
+struct MyEvent;
+
+#[derive(Default)]
+struct SlowHandler {
+    handled: AtomicUsize,
+}
+
+impl SlowHandler {
+    fn handled(&self) -> usize {
+        self.handled.load(Ordering::SeqCst)
+    }
+}
+
+impl EventHandler for SlowHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {
+        thread::sleep(Duration::from_millis(5));
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// Signals when it starts handling an event, and takes its time to handle it.
+struct GatedHandler {
+    started: Mutex<mpsc::Sender<usize>>,
+    handled: AtomicUsize,
+}
+
+impl GatedHandler {
+    fn new() -> (Self, mpsc::Receiver<usize>) {
+        let (started, receiver) = mpsc::channel();
+
+        let handler = Self {
+            started: Mutex::new(started),
+            handled: AtomicUsize::new(0),
+        };
+
+        (handler, receiver)
+    }
+
+    fn handled(&self) -> usize {
+        self.handled.load(Ordering::SeqCst)
+    }
+}
+
+impl EventHandler for GatedHandler {
+    type Event = usize;
+
+    fn handle(&self, event: Self::Event) {
+        let _ = self.started.lock().unwrap().send(event);
+        thread::sleep(Duration::from_millis(50));
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct PanickingHandler;
+
+impl EventHandler for PanickingHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {
+        panic!("can't handle");
+    }
+}
+
+#[yare::parameterized(
+    unbounded = { event_channel() },
+    block = { bounded_event_channel(1, BackpressurePolicy::Block) },
+)]
+fn flush_waits_until_events_are_handled(
+    channel: (
+        storyteller::EventSender<MyEvent>,
+        storyteller::EventReceiver<MyEvent>,
+    ),
+) {
+    let (sender, receiver) = channel;
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = Arc::new(SlowHandler::default());
+    let guard = listener.run_handler(handler.clone());
+
+    reporter.report_event(MyEvent).unwrap();
+    reporter.report_event(MyEvent).unwrap();
+    reporter.flush().unwrap();
+
+    assert_eq!(handler.handled(), 2);
+
+    // The reporter can still be used after flushing
+    reporter.report_event(MyEvent).unwrap();
+    reporter.flush().unwrap();
+
+    assert_eq!(handler.handled(), 3);
+
+    guard.disconnect_and_join(reporter).unwrap();
+}
+
+#[test]
+fn flush_handles_all_events_reported_before() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = Arc::new(SlowHandler::default());
+    let guard = listener.run_handler(handler.clone());
+
+    for _ in 0..5 {
+        reporter.report_event(MyEvent).unwrap();
+    }
+
+    reporter.flush().unwrap();
+    assert_eq!(handler.handled(), 5);
+
+    guard.disconnect_and_join(reporter).unwrap();
+}
+
+#[test]
+fn flush_does_not_drop_barrier_when_full() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::DropNewest);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = Arc::new(SlowHandler::default());
+    let guard = listener.run_handler(handler.clone());
+
+    for _ in 0..3 {
+        reporter.report_event(MyEvent).unwrap();
+    }
+
+    assert!(reporter.flush().is_ok());
+
+    guard.disconnect_and_join(reporter).unwrap();
+}
+
+#[test]
+fn flush_from_handle() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = Arc::new(SlowHandler::default());
+    let guard = listener.run_handler(handler.clone());
+
+    thread::scope(|s| {
+        let handle = reporter.fork();
+
+        s.spawn(move || {
+            for _ in 0..3 {
+                handle.report_event(MyEvent).unwrap();
+            }
+
+            handle.flush().unwrap();
+        });
+    });
+
+    assert_eq!(handler.handled(), 3);
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+}
+
+#[test]
+fn flush_on_dropped_receiver() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    assert_eq!(reporter.flush(), Err(FlushError));
+}
+
+#[test]
+fn flush_drop_oldest_waits_while_reporting_concurrently() {
+    let (sender, receiver) = bounded_event_channel::<usize>(1, BackpressurePolicy::DropOldest);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, started) = GatedHandler::new();
+    let handler = Arc::new(handler);
+    let guard = listener.run_handler(handler.clone());
+
+    reporter.report_event(0usize).unwrap();
+    assert_eq!(started.recv().unwrap(), 0);
+
+    thread::scope(|s| {
+        let handle = reporter.fork();
+
+        // Takes the barrier out of the full channel, while the first event is still being handled
+        s.spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            handle.report_event(1usize).unwrap();
+        });
+
+        reporter.flush().unwrap();
+
+        // The first event was reported before flushing, so it must have been handled
+        assert!(handler.handled() >= 1);
+    });
+
+    guard.disconnect_and_join(reporter).unwrap();
+}
+
+#[yare::parameterized(
+    empty = { 0 },
+    full = { 1 },
+)]
+fn flush_drop_oldest_on_dropped_receiver(queued: usize) {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::DropOldest);
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    for _ in 0..queued {
+        reporter.report_event(MyEvent).unwrap();
+    }
+
+    assert_eq!(reporter.flush(), Err(FlushError));
+}
+
+#[test]
+fn flush_after_handler_panicked() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(PanickingHandler));
+
+    reporter.report_event(MyEvent).unwrap();
+
+    // The listener still holds on to its receiver, but nothing receives the barrier anymore
+    assert_eq!(reporter.flush(), Err(FlushError));
+
+    let token = reporter.disconnect().unwrap();
+    assert!(matches!(guard.join(token), Err(JoinError::Panicked(_))));
+}
@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use storyteller::testing::RecordingHandler;
+use storyteller::{EventHandler, EventHandlerExt};
+
+#[derive(Clone, Debug, PartialEq)]
+enum AppEvent {
+    Progress(u64),
+    Message(&'static str),
+}
+
+fn events() -> Vec<AppEvent> {
+    vec![
+        AppEvent::Message("start"),
+        AppEvent::Progress(10),
+        AppEvent::Progress(90),
+        AppEvent::Message("done"),
+    ]
+}
+
+fn handle_all<H: EventHandler<Event = AppEvent>>(handler: H) {
+    for event in events() {
+        handler.handle(event);
+    }
+
+    handler.finish();
+}
+
+#[test]
+fn filter() {
+    let collector = Arc::new(RecordingHandler::new());
+
+    handle_all(
+        collector
+            .clone()
+            .filter(|event| matches!(event, AppEvent::Message(_))),
+    );
+
+    assert_eq!(
+        collector.events(),
+        vec![AppEvent::Message("start"), AppEvent::Message("done")]
+    );
+    assert_eq!(collector.finish_count(), 1);
+}
+
+#[test]
+fn map() {
+    let collector = Arc::new(RecordingHandler::new());
+
+    handle_all(
+        collector
+            .clone()
+            .map(|event: AppEvent| format!("{:?}", event)),
+    );
+
+    assert_eq!(collector.events().len(), 4);
+    assert_eq!(collector.events()[1], "Progress(10)");
+    assert_eq!(collector.finish_count(), 1);
+}
+
+#[test]
+fn filter_map() {
+    let collector = Arc::new(RecordingHandler::new());
+
+    handle_all(collector.clone().filter_map(|event| match event {
+        AppEvent::Progress(position) => Some(position),
+        AppEvent::Message(_) => None,
+    }));
+
+    assert_eq!(collector.events(), vec![10, 90]);
+    assert_eq!(collector.finish_count(), 1);
+}
+
+#[test]
+fn inspect() {
+    let collector = Arc::new(RecordingHandler::new());
+    let inspected = Arc::new(AtomicUsize::new(0));
+    let counter = inspected.clone();
+
+    handle_all(collector.clone().inspect(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    assert_eq!(inspected.load(Ordering::SeqCst), 4);
+    assert_eq!(collector.events(), events());
+    assert_eq!(collector.finish_count(), 1);
+}
+
+#[test]
+fn pipeline() {
+    let collector = Arc::new(RecordingHandler::new());
+
+    let handler = collector
+        .clone()
+        .filter(|position: &u64| *position > 50)
+        .map(|position: u64| position * 2)
+        .filter_map(|event| match event {
+            AppEvent::Progress(position) => Some(position),
+            AppEvent::Message(_) => None,
+        });
+
+    handle_all(handler);
+
+    assert_eq!(collector.events(), vec![180]);
+    assert_eq!(collector.finish_count(), 1);
+}
@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use storyteller::{
+    event_channel, handler_fn, handler_fn_with_finish, ChannelEventListener, ChannelReporter,
+    EventHandler, EventListener, EventReporter, FanOutHandler,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+struct MyEvent(usize);
+
+#[derive(Default)]
+struct CountingHandler {
+    count: AtomicUsize,
+}
+
+impl EventHandler for CountingHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn run<H>(handler: H, events: usize)
+where
+    H: EventHandler<Event = MyEvent> + 'static,
+{
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(handler));
+
+    for i in 0..events {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+
+    guard.disconnect_and_join(reporter).unwrap();
+}
+
+#[test]
+fn closure_handler() {
+    let events = Arc::new(Mutex::new(vec![]));
+    let collected = events.clone();
+
+    run(
+        handler_fn(move |event: MyEvent| collected.lock().unwrap().push(event)),
+        2,
+    );
+
+    assert_eq!(*events.lock().unwrap(), vec![MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+fn closure_handler_with_finish() {
+    let finished = Arc::new(AtomicBool::new(false));
+    let flag = finished.clone();
+
+    run(
+        handler_fn_with_finish(|_: MyEvent| {}, move || flag.store(true, Ordering::SeqCst)),
+        1,
+    );
+
+    assert!(finished.load(Ordering::SeqCst));
+}
+
+#[test]
+fn arc_handler() {
+    let handler = Arc::new(CountingHandler::default());
+
+    run(handler.clone(), 3);
+
+    assert_eq!(handler.count.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn static_handler() {
+    static HANDLER: CountingHandler = CountingHandler {
+        count: AtomicUsize::new(0),
+    };
+
+    run(&HANDLER, 2);
+
+    assert_eq!(HANDLER.count.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn boxed_handler_tree() {
+    let counter = Arc::new(CountingHandler::default());
+
+    let mut fan_out = FanOutHandler::new();
+    fan_out.add_handler(Box::new(counter.clone()) as Box<dyn EventHandler<Event = MyEvent>>);
+    fan_out.add_handler(Box::new(handler_fn(|_: MyEvent| {})));
+
+    let boxed: Box<dyn EventHandler<Event = MyEvent>> = Box::new(fan_out);
+    run(boxed, 4);
+
+    assert_eq!(counter.count.load(Ordering::SeqCst), 4);
+}
@@ -4,7 +4,7 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use storyteller::{
     event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
-    EventReporter, HandlerGuard,
+    EventReporter, HandlerGuard, JoinError,
 };
 
 #[derive(Debug, Eq, PartialEq)]
@@ -17,6 +17,25 @@ impl EventHandler for NoopHandler {
     fn handle(&self, _: Self::Event) {}
 }
 
+// Panics when handling the event with the given value
+struct PanickingHandler {
+    panic_on: usize,
+}
+
+impl EventHandler for PanickingHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, event: Self::Event) {
+        if event.0 == self.panic_on {
+            panic!("can't handle event {}", event.0);
+        }
+    }
+
+    fn finish(&self) {
+        panic!("can't finish");
+    }
+}
+
 struct TrackingHandler {
     handled: AtomicUsize,
     finished: AtomicBool,
@@ -58,8 +77,7 @@ fn drop_without_join_panics() {
     let reporter = ChannelReporter::new(sender);
     let listener = ChannelEventListener::new(receiver);
     let guard = listener.run_handler(Arc::new(NoopHandler));
-    let token = reporter.disconnect().unwrap();
-    drop(token);
+    let _token = reporter.disconnect().unwrap();
     drop(guard);
 }
 
@@ -98,3 +116,42 @@ fn report_event_on_dropped_receiver() {
     drop(receiver);
     assert!(reporter.report_event(MyEvent(0)).is_err());
 }
+
+#[test]
+fn join_returns_panic_in_handle() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(PanickingHandler { panic_on: 2 }));
+    for i in 0..5 {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+    let token = reporter.disconnect().unwrap();
+
+    match guard.join(token) {
+        Err(JoinError::Panicked(panic)) => {
+            assert_eq!(panic.message(), Some("can't handle event 2"));
+            assert_eq!(panic.events_handled(), 2);
+        }
+        other => panic!("expected a panic, but got: {:?}", other),
+    }
+}
+
+#[test]
+fn disconnect_and_join_returns_panic_in_finish() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(PanickingHandler { panic_on: 5 }));
+    for i in 0..3 {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+
+    match guard.disconnect_and_join(reporter) {
+        Err(JoinError::Panicked(panic)) => {
+            assert_eq!(panic.message(), Some("can't finish"));
+            assert_eq!(panic.events_handled(), 3);
+        }
+        other => panic!("expected a panic, but got: {:?}", other),
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use storyteller::{
+    bounded_event_channel, event_channel, BackpressurePolicy, ChannelEventListener,
+    ChannelReporter, ErrorPolicy, EventHandler, EventListener, EventReporter, FallibleEventHandler,
+    JoinError,
+};
+
+#[derive(Debug)]
+struct Sleep(u64);
+
+struct SleepingHandler {
+    finish_ms: u64,
+}
+
+impl EventHandler for SleepingHandler {
+    type Event = Sleep;
+
+    fn handle(&self, event: Self::Event) {
+        thread::sleep(Duration::from_millis(event.0));
+    }
+
+    fn finish(&self) {
+        thread::sleep(Duration::from_millis(self.finish_ms));
+    }
+}
+
+struct FailingHandler;
+
+impl FallibleEventHandler for FailingHandler {
+    type Event = Sleep;
+    type Error = u64;
+
+    fn handle(&self, event: Self::Event) -> Result<(), Self::Error> {
+        Err(event.0)
+    }
+}
+
+struct PanickingHandler;
+
+impl EventHandler for PanickingHandler {
+    type Event = Sleep;
+
+    fn handle(&self, _event: Self::Event) {
+        panic!("can't handle");
+    }
+}
+
+#[test]
+fn join_with_stats() {
+    let (sender, receiver) = event_channel::<Sleep>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    // Queue the events before the handler runs, so the peak queue depth is known
+    reporter.report_event(Sleep(1)).unwrap();
+    reporter.report_event(Sleep(10)).unwrap();
+    reporter.report_event(Sleep(1)).unwrap();
+
+    let guard = listener.run_handler(Arc::new(SleepingHandler { finish_ms: 5 }));
+
+    let token = reporter.disconnect().unwrap();
+    let stats = guard.join_with_stats(token).unwrap();
+
+    assert_eq!(stats.events_handled(), 3);
+    assert_eq!(stats.peak_queue_depth(), 3);
+    assert!(stats.handle_time() >= Duration::from_millis(12));
+    assert!(stats.max_handle_time() >= Duration::from_millis(10));
+    assert!(stats.max_handle_time() <= stats.handle_time());
+    assert!(stats.finish_time() >= Duration::from_millis(5));
+    assert!(stats.wall_time() >= stats.handle_time() + stats.finish_time());
+}
+
+#[test]
+fn join_with_stats_without_events() {
+    let (sender, receiver) = event_channel::<Sleep>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(SleepingHandler { finish_ms: 0 }));
+
+    let token = reporter.disconnect().unwrap();
+    let stats = guard.join_with_stats(token).unwrap();
+
+    assert_eq!(stats.events_handled(), 0);
+    assert_eq!(stats.peak_queue_depth(), 0);
+    assert_eq!(stats.handle_time(), Duration::ZERO);
+    assert_eq!(stats.max_handle_time(), Duration::ZERO);
+}
+
+#[test]
+fn join_with_stats_returns_panic() {
+    let (sender, receiver) = event_channel::<Sleep>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(PanickingHandler));
+
+    reporter.report_event(Sleep(0)).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    let err = guard.join_with_stats(token).unwrap_err();
+
+    assert!(matches!(err.error(), JoinError::Panicked(_)));
+    assert!(err.stats().is_none());
+}
+
+#[test]
+fn join_with_stats_returns_stats_with_errors() {
+    let (sender, receiver) = event_channel::<Sleep>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    reporter.report_event(Sleep(1)).unwrap();
+    reporter.report_event(Sleep(2)).unwrap();
+
+    let guard = listener.run_fallible_handler(Arc::new(FailingHandler), ErrorPolicy::Collect);
+
+    let token = reporter.disconnect().unwrap();
+    let err = guard.join_with_stats(token).unwrap_err();
+
+    assert_eq!(err.error(), &JoinError::Handler(vec![1, 2]));
+    assert_eq!(err.stats().unwrap().events_handled(), 2);
+    assert_eq!(err.stats().unwrap().peak_queue_depth(), 2);
+}
+
+#[test]
+fn peak_queue_depth_does_not_count_blocked_senders() {
+    let (sender, receiver) = bounded_event_channel::<Sleep>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(SleepingHandler { finish_ms: 0 }));
+
+    // Most senders wait for room in the channel, while the handler takes its time
+    thread::scope(|s| {
+        for _ in 0..6 {
+            let handle = reporter.fork();
+            s.spawn(move || handle.report_event(Sleep(30)).unwrap());
+        }
+    });
+
+    let token = reporter.disconnect().unwrap();
+    let stats = guard.join_with_stats(token).unwrap();
+
+    assert_eq!(stats.events_handled(), 6);
+    assert!(stats.peak_queue_depth() <= 1);
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+use storyteller::testing::RecordingHandler;
+use storyteller::{
+    inline_pair, EventHandler, EventListener, EventReporter, HandlerGuard, InlineEventListener,
+    InlineHandlerGuard, InlineReporterError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MyEvent(usize);
+
+#[test]
+fn handles_events_while_reporting() {
+    let (reporter, listener) = inline_pair::<MyEvent>();
+
+    let handler = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    for i in 0..3 {
+        reporter.report_event(MyEvent(i)).unwrap();
+        assert_eq!(handler.len(), i + 1);
+    }
+
+    reporter.disconnect().unwrap();
+    assert!(!handler.is_finished());
+
+    guard.join(()).unwrap();
+
+    handler.assert_events_eq(&[MyEvent(0), MyEvent(1), MyEvent(2)]);
+    handler.assert_finished();
+}
+
+#[test]
+fn report_event_without_handler() {
+    let (reporter, _listener) = inline_pair::<MyEvent>();
+
+    let err = reporter.report_event(MyEvent(0)).unwrap_err();
+
+    assert!(matches!(err, InlineReporterError::NoHandler(MyEvent(0))));
+}
+
+// Hands over to the next handler, when it handles its first event.
+struct HandOverHandler {
+    listener: Arc<InlineEventListener<MyEvent>>,
+    next: Arc<RecordingHandler<MyEvent>>,
+    guard: Mutex<Option<InlineHandlerGuard<MyEvent>>>,
+}
+
+impl EventHandler for HandOverHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {
+        let guard = self.listener.run_handler(self.next.clone());
+        *self.guard.lock().unwrap() = Some(guard);
+    }
+}
+
+#[test]
+fn handler_can_run_another_handler() {
+    let (reporter, listener) = inline_pair::<MyEvent>();
+    let listener = Arc::new(listener);
+    let next = Arc::new(RecordingHandler::new());
+
+    let handler = Arc::new(HandOverHandler {
+        listener: listener.clone(),
+        next: next.clone(),
+        guard: Mutex::new(None),
+    });
+
+    let guard = listener.run_handler(handler.clone());
+    reporter.report_event(MyEvent(0)).unwrap();
+    reporter.report_event(MyEvent(1)).unwrap();
+
+    reporter.disconnect().unwrap();
+    guard.join(()).unwrap();
+
+    let next_guard = handler.guard.lock().unwrap().take().unwrap();
+    next_guard.join(()).unwrap();
+
+    next.assert_events_eq(&[MyEvent(1)]);
+    next.assert_finished();
+}
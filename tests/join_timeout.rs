@@ -0,0 +1,199 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use storyteller::{
+    bounded_event_channel, event_channel, BackpressurePolicy, ChannelEventListener,
+    ChannelReporter, EventHandler, EventListener, EventReporter, HandlerGuard, JoinError,
+    JoinTimeoutError,
+};
+
+#[derive(Debug)]
+struct MyEvent(usize);
+
+// Blocks on each event, until it is released
+struct GatedHandler {
+    started: Mutex<Sender<usize>>,
+    release: Mutex<Receiver<()>>,
+}
+
+impl GatedHandler {
+    fn new() -> (Self, Receiver<usize>, Sender<()>) {
+        let (started_tx, started_rx) = channel();
+        let (release_tx, release_rx) = channel();
+
+        let handler = Self {
+            started: Mutex::new(started_tx),
+            release: Mutex::new(release_rx),
+        };
+
+        (handler, started_rx, release_tx)
+    }
+}
+
+impl EventHandler for GatedHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, event: Self::Event) {
+        self.started.lock().unwrap().send(event.0).unwrap();
+        let _ = self.release.lock().unwrap().recv();
+    }
+}
+
+struct PanicOnFinish;
+
+impl EventHandler for PanicOnFinish {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {}
+
+    fn finish(&self) {
+        panic!("can't finish");
+    }
+}
+
+#[test]
+fn join_timeout_when_finished_in_time() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, _started, release) = GatedHandler::new();
+    let guard = listener.run_handler(Arc::new(handler));
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    release.send(()).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    let result = guard.join_timeout(token, Duration::from_secs(10));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn join_timeout_hands_back_guard_and_token() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, started, release) = GatedHandler::new();
+    let guard = listener.run_handler(Arc::new(handler));
+
+    for i in 0..3 {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+
+    // The first event is being handled, the others are still queued
+    assert_eq!(started.recv().unwrap(), 0);
+
+    let token = reporter.disconnect().unwrap();
+
+    let (guard, token) = match guard.join_timeout(token, Duration::from_millis(10)) {
+        Err(JoinTimeoutError::TimedOut { guard, token }) => (guard, token),
+        other => panic!("expected a timeout, got {:?}", other),
+    };
+
+    assert_eq!(guard.events_queued(), 2);
+    assert_eq!(guard.events_handled(), 0);
+
+    for _ in 0..3 {
+        release.send(()).unwrap();
+    }
+
+    assert!(guard.join(token).is_ok());
+}
+
+#[test]
+fn join_deadline_in_the_past_times_out() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, started, release) = GatedHandler::new();
+    let guard = listener.run_handler(Arc::new(handler));
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    started.recv().unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    let result: Result<(), JoinTimeoutError> = guard.join_deadline(token, Instant::now());
+
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "handler did not finish in time, with 0 event(s) still queued"
+    );
+
+    release.send(()).unwrap();
+
+    if let JoinTimeoutError::TimedOut { guard, token } = err {
+        assert!(guard.join(token).is_ok());
+    }
+}
+
+#[test]
+fn abandon_does_not_panic() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, started, _release) = GatedHandler::new();
+    let guard = listener.run_handler(Arc::new(handler));
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    started.recv().unwrap();
+
+    let token = reporter.disconnect().unwrap();
+
+    match guard.join_timeout(token, Duration::from_millis(10)) {
+        Err(JoinTimeoutError::TimedOut { guard, .. }) => guard.abandon(),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+}
+
+#[test]
+fn join_timeout_returns_panic() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(PanicOnFinish));
+
+    let token = reporter.disconnect().unwrap();
+
+    match guard.join_timeout(token, Duration::from_secs(10)) {
+        Err(JoinTimeoutError::Failed(JoinError::Panicked(panic))) => {
+            assert_eq!(panic.message(), Some("can't finish"));
+        }
+        other => panic!("expected a panic, got {:?}", other),
+    }
+}
+
+#[test]
+fn events_queued_does_not_count_blocked_senders() {
+    let (sender, receiver) = bounded_event_channel::<MyEvent>(1, BackpressurePolicy::Block);
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let (handler, started, release) = GatedHandler::new();
+    let guard = listener.run_handler(Arc::new(handler));
+
+    reporter.report_event(MyEvent(0)).unwrap();
+    assert_eq!(started.recv().unwrap(), 0);
+
+    thread::scope(|s| {
+        for i in 1..7 {
+            let handle = reporter.fork();
+            s.spawn(move || handle.report_event(MyEvent(i)).unwrap());
+        }
+
+        // One event fits in the channel, the other senders wait for room
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(guard.events_queued(), 1);
+
+        for _ in 1..7 {
+            release.send(()).unwrap();
+            started.recv().unwrap();
+            assert!(guard.events_queued() <= 1);
+        }
+    });
+
+    release.send(()).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    assert!(guard.join(token).is_ok());
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
+    EventReporter, JoinError, JsonErrorPolicy, JsonLinesError, JsonLinesHandler,
+};
+
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Event {
+    Message { text: String },
+    Done,
+}
+
+// Serializing a map with non-string keys to JSON fails
+#[derive(serde::Serialize)]
+struct Unserializable(BTreeMap<(u8, u8), u8>);
+
+impl Unserializable {
+    fn new() -> Self {
+        Self(vec![((0, 0), 0)].into_iter().collect())
+    }
+}
+
+/// A writer which can be inspected while the handler holds on to it.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn lines(&self) -> Vec<String> {
+        String::from_utf8(self.0.lock().unwrap().clone())
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::new(io::ErrorKind::Other, "disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "disk full"))
+    }
+}
+
+#[test]
+fn writes_json_lines() {
+    let buffer = SharedBuffer::default();
+
+    let (sender, receiver) = event_channel::<Event>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let guard = listener.run_handler(Arc::new(JsonLinesHandler::new(buffer.clone())));
+
+    reporter
+        .report_event(Event::Message {
+            text: "say \"hi\"".to_string(),
+        })
+        .unwrap();
+    reporter.report_event(Event::Done).unwrap();
+
+    guard.disconnect_and_join(reporter).unwrap();
+
+    assert_eq!(
+        buffer.lines(),
+        vec![
+            r#"{"type":"message","text":"say \"hi\""}"#,
+            r#"{"type":"done"}"#,
+        ]
+    );
+}
+
+#[test]
+fn with_schema() {
+    let handler = JsonLinesHandler::new(Vec::new()).with_schema("my-tool", 2);
+
+    handler.handle(Event::Done);
+
+    assert_eq!(
+        String::from_utf8(handler.into_inner()).unwrap(),
+        "{\"schema\":\"my-tool\",\"version\":2,\"event\":{\"type\":\"done\"}}\n"
+    );
+}
+
+#[test]
+fn skip_serialize_error() {
+    let handler = JsonLinesHandler::new(Vec::new());
+
+    handler.handle(Unserializable::new());
+
+    assert!(handler.take_errors().is_empty());
+    assert!(handler.into_inner().is_empty());
+}
+
+#[test]
+fn collect_errors() {
+    let handler = JsonLinesHandler::new(FailingWriter).with_error_policy(JsonErrorPolicy::Collect);
+
+    handler.handle(Event::Done);
+    handler.finish();
+
+    let errors = handler.take_errors();
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(errors[0], JsonLinesError::Io(_)));
+    assert_eq!(errors[1].to_string(), "unable to write event: disk full");
+
+    assert!(handler.take_errors().is_empty());
+}
+
+#[test]
+fn collect_serialize_error_without_partial_line() {
+    let handler = JsonLinesHandler::new(Vec::new()).with_error_policy(JsonErrorPolicy::Collect);
+
+    handler.handle(Unserializable::new());
+
+    let errors = handler.take_errors();
+    assert!(matches!(errors[..], [JsonLinesError::Serialize(_)]));
+    assert!(handler.into_inner().is_empty());
+}
+
+#[test]
+fn panic_on_error() {
+    let (sender, receiver) = event_channel::<Unserializable>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = JsonLinesHandler::new(Vec::new()).with_error_policy(JsonErrorPolicy::Panic);
+    let guard = listener.run_handler(Arc::new(handler));
+
+    reporter.report_event(Unserializable::new()).unwrap();
+
+    match guard.disconnect_and_join(reporter) {
+        Err(JoinError::Panicked(panic)) => {
+            assert!(panic
+                .message()
+                .unwrap()
+                .starts_with("unable to serialize event"));
+        }
+        other => panic!("expected a panic, got {:?}", other),
+    }
+}
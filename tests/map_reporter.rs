@@ -0,0 +1,65 @@
+use storyteller::{
+    event_channel, ChannelReporter, EventReporter, EventReporterError, EventReporterExt,
+    WithContext,
+};
+
+#[derive(Debug, PartialEq)]
+enum LibEvent {
+    Started,
+    Progress(u32),
+}
+
+#[derive(Debug, PartialEq)]
+enum AppEvent {
+    Lib(LibEvent),
+    Message(&'static str),
+}
+
+fn library<R: EventReporter<Event = LibEvent>>(reporter: &R) {
+    let _ = reporter.report_event(LibEvent::Started);
+    let _ = reporter.report_event(LibEvent::Progress(50));
+}
+
+#[test]
+fn map_library_events() {
+    let (sender, receiver) = event_channel::<AppEvent>();
+    let reporter = ChannelReporter::new(sender);
+
+    reporter.report_event(AppEvent::Message("before")).unwrap();
+    library(&reporter.map(AppEvent::Lib));
+
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Message("before"));
+    assert_eq!(receiver.recv().unwrap(), AppEvent::Lib(LibEvent::Started));
+    assert_eq!(
+        receiver.recv().unwrap(),
+        AppEvent::Lib(LibEvent::Progress(50))
+    );
+}
+
+#[test]
+fn map_returns_error_of_parent() {
+    let (sender, receiver) = event_channel::<AppEvent>();
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    let mapped = reporter.map(AppEvent::Lib);
+    let err = mapped.report_event(LibEvent::Started).unwrap_err();
+
+    assert!(matches!(
+        err,
+        EventReporterError::Disconnected(AppEvent::Lib(LibEvent::Started))
+    ));
+}
+
+#[test]
+fn map_within_context() {
+    let (sender, receiver) = event_channel::<WithContext<&str, AppEvent>>();
+    let reporter = ChannelReporter::new(sender);
+
+    let member = reporter.with_context("member-a");
+    library(&member.map(|event| AppEvent::Lib(event).into()));
+
+    let event = receiver.recv().unwrap();
+    assert_eq!(event.event(), &AppEvent::Lib(LibEvent::Started));
+    assert_eq!(event.contexts(), &["member-a"]);
+}
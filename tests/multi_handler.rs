@@ -6,48 +6,9 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use storyteller::{
     event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
-    EventReporter, HandlerGuard,
+    EventReporter, FanOutHandler, HandlerGuard,
 };
 
-// Caution: does only check whether `received` events match expected events
-// Must also use `ChannelHandlerGuard::join` to ensure panic's are caught.
-struct MultiHandler<EventT: Clone> {
-    handlers: Vec<Box<dyn EventHandler<Event = EventT>>>,
-}
-
-impl<EventT: Clone + Sync> MultiHandler<EventT> {
-    fn new() -> Self {
-        Self { handlers: vec![] }
-    }
-
-    fn add_handler(&mut self, handler: Box<dyn EventHandler<Event = EventT>>) {
-        self.handlers.push(handler);
-    }
-
-    // SAFETY:
-    // Here we downcast to get our handler type H back for unit testing, 🤞
-    #[cfg(test)]
-    unsafe fn get_handler<H>(&self, nth: usize) -> &H {
-        &*(&*self.handlers[nth] as *const dyn EventHandler<Event = EventT> as *const H)
-    }
-}
-
-impl<EventT: Clone + Sync> EventHandler for MultiHandler<EventT> {
-    type Event = EventT;
-
-    fn handle(&self, event: Self::Event) {
-        for handler in &self.handlers {
-            handler.handle(event.clone())
-        }
-    }
-
-    fn finish(&self) {
-        for handler in &self.handlers {
-            handler.finish();
-        }
-    }
-}
-
 struct CountingHandler<EventT: Sync> {
     counter: AtomicUsize,
     phantom: PhantomData<EventT>,
@@ -121,13 +82,13 @@ fn finish_delegates_to_sub_handlers() {
     let listener = ChannelEventListener::new(event_receiver);
     let flag1 = Arc::new(AtomicBool::new(false));
     let flag2 = Arc::new(AtomicBool::new(false));
-    let mut multi_handler = MultiHandler::<usize>::new();
-    multi_handler.add_handler(Box::new(FinishFlagHandler {
+    let mut multi_handler = FanOutHandler::<usize>::new();
+    multi_handler.add_handler(FinishFlagHandler {
         finished: flag1.clone(),
-    }));
-    multi_handler.add_handler(Box::new(FinishFlagHandler {
+    });
+    multi_handler.add_handler(FinishFlagHandler {
         finished: flag2.clone(),
-    }));
+    });
     let fin = listener.run_handler(Arc::new(multi_handler));
     let token = reporter.disconnect().unwrap();
     fin.join(token).unwrap();
@@ -146,9 +107,9 @@ fn test() {
     let counter2 = SummingHandler::<usize>::new();
 
     let multi_handler = {
-        let mut multi_handler = MultiHandler::<usize>::new();
-        multi_handler.add_handler(Box::new(counter1));
-        multi_handler.add_handler(Box::new(counter2));
+        let mut multi_handler = FanOutHandler::<usize>::new();
+        multi_handler.add_handler(counter1);
+        multi_handler.add_handler(counter2);
         multi_handler
     };
 
@@ -164,9 +125,20 @@ fn test() {
 
     // NB: Order of these statements is important. The assertions must be placed after
     // join() to ensure all expected events have been processed
-    let c1 = unsafe { handler.get_handler::<CountingHandler<usize>>(0) };
+    let c1 = handler.get::<CountingHandler<usize>>(0).unwrap();
     assert_eq!(c1.count(), 5);
 
-    let c2 = unsafe { handler.get_handler::<SummingHandler<usize>>(1) };
+    let c2 = handler.get::<SummingHandler<usize>>(1).unwrap();
     assert_eq!(c2.count(), 10);
 }
+
+#[test]
+fn get_with_wrong_type() {
+    let mut multi_handler = FanOutHandler::<usize>::new();
+    let nth = multi_handler.add_handler(CountingHandler::<usize>::new());
+
+    assert!(multi_handler.get::<SummingHandler<usize>>(nth).is_none());
+    assert!(multi_handler
+        .get::<CountingHandler<usize>>(nth + 1)
+        .is_none());
+}
@@ -0,0 +1,125 @@
+use indicatif::{MultiProgress, ProgressDrawTarget};
+use std::sync::Arc;
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
+    EventReporter, ProgressHandler, ProgressMapping, ProgressUpdate,
+};
+
+enum Event {
+    Start { task: u8, length: u64 },
+    Step { task: u8 },
+    Restart { task: u8 },
+    Done { task: u8 },
+    Fail { task: u8 },
+    Log(&'static str),
+}
+
+struct Mapping;
+
+impl ProgressMapping for Mapping {
+    type Event = Event;
+    type Task = u8;
+
+    fn map(&self, event: Self::Event) -> Vec<ProgressUpdate<u8>> {
+        match event {
+            Event::Start { task, length } => vec![
+                ProgressUpdate::SetLength { task, length },
+                ProgressUpdate::SetMessage {
+                    task,
+                    message: format!("task {}", task).into(),
+                },
+            ],
+            Event::Step { task } => vec![ProgressUpdate::Increment { task, delta: 1 }],
+            Event::Restart { task } => vec![ProgressUpdate::Reset { task }],
+            Event::Done { task } => vec![ProgressUpdate::Finish { task }],
+            Event::Fail { task } => vec![ProgressUpdate::Abandon { task }],
+            Event::Log(message) => vec![ProgressUpdate::Println(message.into())],
+        }
+    }
+}
+
+fn hidden_handler() -> ProgressHandler<Mapping> {
+    let multi = MultiProgress::with_draw_target(ProgressDrawTarget::hidden());
+    ProgressHandler::with_multi_progress(Mapping, multi)
+}
+
+#[test]
+fn updates_bar_of_task() {
+    let handler = hidden_handler();
+
+    handler.handle(Event::Start { task: 1, length: 3 });
+    handler.handle(Event::Step { task: 1 });
+    handler.handle(Event::Step { task: 1 });
+
+    let bar = handler.bar(&1).unwrap();
+    assert_eq!(bar.length(), Some(3));
+    assert_eq!(bar.position(), 2);
+    assert_eq!(bar.message(), "task 1");
+
+    handler.handle(Event::Restart { task: 1 });
+    assert_eq!(bar.position(), 0);
+
+    assert!(handler.bar(&2).is_none());
+}
+
+#[test]
+fn bar_per_task() {
+    let handler = hidden_handler();
+
+    handler.handle(Event::Start { task: 1, length: 2 });
+    handler.handle(Event::Start { task: 2, length: 4 });
+    handler.handle(Event::Step { task: 2 });
+    handler.handle(Event::Log("not a task"));
+
+    assert_eq!(handler.bar(&1).unwrap().position(), 0);
+    assert_eq!(handler.bar(&2).unwrap().position(), 1);
+    assert_eq!(handler.bar(&2).unwrap().length(), Some(4));
+}
+
+#[test]
+fn explicit_finish_and_abandon() {
+    let handler = hidden_handler();
+
+    handler.handle(Event::Start { task: 1, length: 2 });
+    handler.handle(Event::Done { task: 1 });
+    handler.handle(Event::Start { task: 2, length: 2 });
+    handler.handle(Event::Fail { task: 2 });
+
+    let done = handler.bar(&1).unwrap();
+    assert!(done.is_finished());
+    // Finishing a bar moves it to its length
+    assert_eq!(done.position(), 2);
+
+    let failed = handler.bar(&2).unwrap();
+    assert!(failed.is_finished());
+    assert_eq!(failed.position(), 0);
+}
+
+#[test]
+fn finish_completes_or_abandons_remaining_bars() {
+    let (sender, receiver) = event_channel::<Event>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+    let handler = Arc::new(hidden_handler());
+    let guard = listener.run_handler(handler.clone());
+
+    reporter
+        .report_event(Event::Start { task: 1, length: 1 })
+        .unwrap();
+    reporter.report_event(Event::Step { task: 1 }).unwrap();
+    reporter
+        .report_event(Event::Start { task: 2, length: 3 })
+        .unwrap();
+    reporter.report_event(Event::Step { task: 2 }).unwrap();
+
+    guard.disconnect_and_join(reporter).unwrap();
+
+    let complete = handler.bar(&1).unwrap();
+    assert!(complete.is_finished());
+    assert_eq!(complete.position(), 1);
+
+    // An abandoned bar is left at its position
+    let incomplete = handler.bar(&2).unwrap();
+    assert!(incomplete.is_finished());
+    assert_eq!(incomplete.position(), 1);
+}
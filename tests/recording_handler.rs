@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use storyteller::testing::RecordingHandler;
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
+    EventReporter, HandlerGuard,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct MyEvent(usize);
+
+fn run(events: usize) -> Arc<RecordingHandler<MyEvent>> {
+    let (event_sender, event_receiver) = event_channel::<MyEvent>();
+
+    let reporter = ChannelReporter::new(event_sender);
+    let listener = ChannelEventListener::new(event_receiver);
+
+    let handler = Arc::new(RecordingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    for i in 0..events {
+        reporter.report_event(MyEvent(i)).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    handler
+}
+
+#[test]
+fn records_events() {
+    let handler = run(3);
+
+    handler.assert_events_eq(&[MyEvent(0), MyEvent(1), MyEvent(2)]);
+    handler.assert_contains(&MyEvent(1));
+    handler.assert_finished();
+    assert_eq!(handler.events(), vec![MyEvent(0), MyEvent(1), MyEvent(2)]);
+}
+
+#[test]
+fn records_no_events() {
+    let handler = run(0);
+
+    assert!(handler.is_empty());
+    handler.assert_finished();
+}
+
+#[test]
+fn counts_finish_calls() {
+    let handler = RecordingHandler::<MyEvent>::new();
+    assert_eq!(handler.finish_count(), 0);
+
+    handler.finish();
+    handler.finish();
+
+    assert_eq!(handler.finish_count(), 2);
+    assert!(handler.is_finished());
+}
+
+#[test]
+#[should_panic]
+fn assert_events_eq_failure() {
+    run(3).assert_events_eq(&[MyEvent(0), MyEvent(1)]);
+}
+
+#[test]
+#[should_panic]
+fn assert_contains_failure() {
+    run(3).assert_contains(&MyEvent(3));
+}
+
+#[test]
+#[should_panic]
+fn assert_finished_failure() {
+    RecordingHandler::<MyEvent>::new().assert_finished();
+}
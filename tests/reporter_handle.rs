@@ -0,0 +1,90 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use storyteller::{
+    event_channel, ChannelEventListener, ChannelReporter, EventHandler, EventListener,
+    EventReporter, HandlerGuard,
+};
+
+struct CountingHandler {
+    count: AtomicUsize,
+}
+
+impl CountingHandler {
+    fn new() -> Self {
+        Self {
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    fn count(&self) -> usize {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+impl EventHandler for CountingHandler {
+    type Event = usize;
+
+    fn handle(&self, _event: Self::Event) {
+        self.count.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn report_from_many_threads() {
+    let (sender, receiver) = event_channel::<usize>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let handler = Arc::new(CountingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    thread::scope(|s| {
+        for _ in 0..4 {
+            let handle = reporter.fork();
+            s.spawn(move || {
+                for i in 0usize..25 {
+                    handle.report_event(i).unwrap();
+                }
+            });
+        }
+
+        reporter.report_event(0usize).unwrap();
+    });
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    assert_eq!(handler.count(), 101);
+}
+
+#[test]
+fn disconnect_after_handles_are_gone() {
+    let (sender, receiver) = event_channel::<usize>();
+    let reporter = ChannelReporter::new(sender);
+    let listener = ChannelEventListener::new(receiver);
+
+    let handler = Arc::new(CountingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    thread::scope(|s| {
+        let handle = reporter.fork();
+        let cloned = handle.clone();
+
+        s.spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            handle.report_event(1usize).unwrap();
+            handle.disconnect().unwrap();
+
+            thread::sleep(Duration::from_millis(50));
+            cloned.report_event(2usize).unwrap();
+        });
+    });
+
+    // The handles borrow the reporter, so they're gone by now, and disconnecting doesn't block
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).unwrap();
+
+    assert_eq!(handler.count(), 2);
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+use storyteller::testing::RecordingHandler;
+use storyteller::{EventHandler, RouterHandler};
+
+#[derive(Clone, Debug, PartialEq)]
+enum AppEvent {
+    Download(u64),
+    Build(&'static str),
+    Diagnostic(&'static str),
+    Done,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum Route {
+    Download,
+    Build,
+    Diagnostic,
+    Other,
+}
+
+fn classify(event: &AppEvent) -> Route {
+    match event {
+        AppEvent::Download(_) => Route::Download,
+        AppEvent::Build(_) => Route::Build,
+        AppEvent::Diagnostic(_) => Route::Diagnostic,
+        AppEvent::Done => Route::Other,
+    }
+}
+
+fn handle_all(router: &RouterHandler<AppEvent, Route>) {
+    for event in [
+        AppEvent::Download(1),
+        AppEvent::Build("a"),
+        AppEvent::Diagnostic("warning"),
+        AppEvent::Done,
+    ] {
+        router.handle(event);
+    }
+
+    router.finish();
+}
+
+#[test]
+fn routes_events() {
+    let downloads = Arc::new(RecordingHandler::new());
+    let builds = Arc::new(RecordingHandler::new());
+    let log = Arc::new(RecordingHandler::new());
+    let fallback = Arc::new(RecordingHandler::new());
+
+    let mut router = RouterHandler::new(classify);
+    router.add_route(Route::Download, downloads.clone());
+    router.add_routes([Route::Build, Route::Diagnostic], builds.clone());
+    router.add_catch_all(log.clone());
+    router.add_fallback(fallback.clone());
+
+    handle_all(&router);
+
+    assert_eq!(downloads.events(), vec![AppEvent::Download(1)]);
+    assert_eq!(
+        builds.events(),
+        vec![AppEvent::Build("a"), AppEvent::Diagnostic("warning")]
+    );
+    assert_eq!(log.events().len(), 4);
+    assert_eq!(fallback.events(), vec![AppEvent::Done]);
+
+    for handler in [&downloads, &builds, &log, &fallback] {
+        assert_eq!(handler.finish_count(), 1);
+    }
+}
+
+#[test]
+fn handler_for_repeated_route_receives_event_once() {
+    let handler = Arc::new(RecordingHandler::new());
+
+    let mut router = RouterHandler::new(classify);
+    router.add_routes([Route::Download, Route::Download], handler.clone());
+
+    handle_all(&router);
+
+    assert_eq!(handler.events(), vec![AppEvent::Download(1)]);
+    assert_eq!(handler.finish_count(), 1);
+}
+
+#[test]
+fn unrouted_events_without_fallback_are_discarded() {
+    let handler = Arc::new(RecordingHandler::new());
+
+    let mut router = RouterHandler::new(classify);
+    router.add_route(Route::Build, handler.clone());
+
+    handle_all(&router);
+
+    assert_eq!(handler.events(), vec![AppEvent::Build("a")]);
+}
+
+#[test]
+fn get_handler() {
+    let mut router = RouterHandler::new(classify);
+    let index = router.add_route(Route::Build, RecordingHandler::new());
+
+    handle_all(&router);
+
+    assert_eq!(router.len(), 1);
+
+    let handler = router.get::<RecordingHandler<AppEvent>>(index).unwrap();
+    assert_eq!(handler.events(), vec![AppEvent::Build("a")]);
+    assert_eq!(handler.finish_count(), 1);
+
+    assert!(router
+        .get::<Arc<RecordingHandler<AppEvent>>>(index)
+        .is_none());
+    assert!(router
+        .get::<RecordingHandler<AppEvent>>(index + 1)
+        .is_none());
+}
@@ -0,0 +1,78 @@
+use std::panic;
+use std::sync::Arc;
+use storyteller::testing::RecordingHandler;
+use storyteller::{EventHandler, EventReporter, JoinError};
+
+#[derive(Debug, Eq, PartialEq)]
+struct MyEvent(usize);
+
+struct PanickingHandler;
+
+impl EventHandler for PanickingHandler {
+    type Event = MyEvent;
+
+    fn handle(&self, _event: Self::Event) {
+        panic!("can't handle");
+    }
+}
+
+#[test]
+fn scope_returns_closure_result() {
+    let handler = Arc::new(RecordingHandler::new());
+
+    let (value, joined) = storyteller::scope(handler.clone(), |reporter| {
+        for i in 0..3 {
+            reporter.report_event(MyEvent(i)).unwrap();
+        }
+
+        "done"
+    });
+
+    assert_eq!(value, "done");
+    assert!(joined.is_ok());
+    handler.assert_events_eq(&[MyEvent(0), MyEvent(1), MyEvent(2)]);
+    handler.assert_finished();
+}
+
+#[test]
+fn scope_returns_handler_panic() {
+    let (value, joined) = storyteller::scope(Arc::new(PanickingHandler), |reporter| {
+        reporter.report_event(MyEvent(0)).unwrap();
+        1
+    });
+
+    assert_eq!(value, 1);
+    assert!(matches!(joined, Err(JoinError::Panicked(_))));
+}
+
+#[test]
+fn scope_with_handles() {
+    let handler = Arc::new(RecordingHandler::new());
+
+    let ((), joined) = storyteller::scope(handler.clone(), |reporter| {
+        std::thread::scope(|s| {
+            let handle = reporter.fork();
+            s.spawn(move || handle.report_event(MyEvent(1)).unwrap());
+        });
+    });
+
+    assert!(joined.is_ok());
+    handler.assert_events_eq(&[MyEvent(1)]);
+}
+
+#[test]
+fn scope_handles_events_before_resuming_panic() {
+    let handler = Arc::new(RecordingHandler::new());
+    let scoped = handler.clone();
+
+    let result = panic::catch_unwind(move || {
+        storyteller::scope(scoped, |reporter| {
+            reporter.report_event(MyEvent(0)).unwrap();
+            panic!("closure panicked");
+        })
+    });
+
+    assert!(result.is_err());
+    handler.assert_events_eq(&[MyEvent(0)]);
+    handler.assert_finished();
+}
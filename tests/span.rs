@@ -0,0 +1,91 @@
+use std::time::Duration;
+use storyteller::{
+    event_channel, ChannelReporter, EventReceiver, EventReporter, EventReporterExt, SpanEvent,
+    SpanId,
+};
+
+#[derive(Debug, PartialEq)]
+enum MyEvent {
+    Message(&'static str),
+    Span(SpanEvent<&'static str>),
+}
+
+impl From<SpanEvent<&'static str>> for MyEvent {
+    fn from(event: SpanEvent<&'static str>) -> Self {
+        Self::Span(event)
+    }
+}
+
+fn start(
+    receiver: &EventReceiver<MyEvent>,
+) -> (SpanId, Option<SpanId>, &'static str, &'static str) {
+    match receiver.recv().unwrap() {
+        MyEvent::Span(SpanEvent::Start {
+            id,
+            parent,
+            name,
+            payload,
+        }) => (id, parent, name, payload),
+        other => panic!("expected a span start, got {:?}", other),
+    }
+}
+
+fn end(receiver: &EventReceiver<MyEvent>) -> (SpanId, Duration) {
+    match receiver.recv().unwrap() {
+        MyEvent::Span(SpanEvent::End { id, duration }) => (id, duration),
+        other => panic!("expected a span end, got {:?}", other),
+    }
+}
+
+#[test]
+fn nested_spans() {
+    let (sender, receiver) = event_channel::<MyEvent>();
+    let reporter = ChannelReporter::new(sender);
+
+    {
+        let resolve = reporter.span("resolve", "root").unwrap();
+        reporter
+            .report_event(MyEvent::Message("resolving"))
+            .unwrap();
+
+        {
+            let download = resolve.child("download", "crate-a").unwrap();
+            std::thread::sleep(Duration::from_millis(5));
+            download.end().unwrap();
+        }
+
+        let _build = resolve.child("build", "crate-b").unwrap();
+    }
+
+    let (resolve, parent, name, payload) = start(&receiver);
+    assert_eq!((parent, name, payload), (None, "resolve", "root"));
+
+    assert_eq!(receiver.recv().unwrap(), MyEvent::Message("resolving"));
+
+    let (download, parent, name, payload) = start(&receiver);
+    assert_eq!(
+        (parent, name, payload),
+        (Some(resolve), "download", "crate-a")
+    );
+
+    let (id, duration) = end(&receiver);
+    assert_eq!(id, download);
+    assert!(duration >= Duration::from_millis(5));
+
+    let (build, parent, name, _) = start(&receiver);
+    assert_eq!((parent, name), (Some(resolve), "build"));
+    assert_ne!(build, download);
+
+    // Guards are dropped in reverse order of creation
+    assert_eq!(end(&receiver).0, build);
+    assert_eq!(end(&receiver).0, resolve);
+}
+
+#[test]
+fn span_on_disconnected_reporter() {
+    let (sender, receiver) = event_channel::<SpanEvent<()>>();
+    let reporter = ChannelReporter::new(sender);
+    drop(receiver);
+
+    assert!(reporter.span("span", ()).is_err());
+}
@@ -0,0 +1,140 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use storyteller::{
+    tokio_event_channel, EventHandler, EventListener, EventReporter, HandlerGuard,
+    TokioEventListener, TokioJoinError, TokioReporter,
+};
+
+struct TrackingHandler {
+    handled: AtomicUsize,
+    finished: AtomicBool,
+}
+
+impl TrackingHandler {
+    fn new() -> Self {
+        Self {
+            handled: AtomicUsize::new(0),
+            finished: AtomicBool::new(false),
+        }
+    }
+
+    fn handled(&self) -> usize {
+        self.handled.load(Ordering::SeqCst)
+    }
+
+    fn finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+}
+
+impl EventHandler for TrackingHandler {
+    type Event = usize;
+
+    fn handle(&self, _: Self::Event) {
+        self.handled.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn finish(&self) {
+        self.finished.store(true, Ordering::SeqCst);
+    }
+}
+
+struct PanickingHandler;
+
+impl EventHandler for PanickingHandler {
+    type Event = usize;
+
+    fn handle(&self, event: Self::Event) {
+        if event > 0 {
+            panic!("handler panicked");
+        }
+    }
+}
+
+#[tokio::test]
+async fn join_async() {
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let handler = Arc::new(TrackingHandler::new());
+    let guard = listener.run_handler(handler.clone());
+
+    for i in 0usize..5 {
+        reporter.report_event(i).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    guard.join(token).await.unwrap();
+
+    assert_eq!(handler.handled(), 5);
+    assert!(handler.finished());
+}
+
+#[tokio::test]
+async fn join_async_after_panic() {
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let guard = listener.run_handler(Arc::new(PanickingHandler));
+    reporter.report_event(0usize).unwrap();
+    reporter.report_event(1usize).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    let err = guard.join(token).await.unwrap_err();
+
+    match err {
+        TokioJoinError::Panicked(panic) => {
+            assert_eq!(panic.message(), Some("handler panicked"));
+            assert_eq!(panic.events_handled(), 1);
+        }
+        other => panic!("expected a panic, got {:?}", other),
+    }
+}
+
+#[test]
+fn join_blocking() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let handler = Arc::new(TrackingHandler::new());
+    let guard = {
+        let _context = runtime.enter();
+        listener.run_handler(handler.clone())
+    };
+
+    for i in 0usize..5 {
+        reporter.report_event(i).unwrap();
+    }
+
+    let token = reporter.disconnect().unwrap();
+    HandlerGuard::join(guard, token).unwrap();
+
+    assert_eq!(handler.handled(), 5);
+    assert!(handler.finished());
+}
+
+#[test]
+fn join_blocking_after_panic() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let (sender, receiver) = tokio_event_channel::<usize>();
+    let reporter = TokioReporter::new(sender);
+    let listener = TokioEventListener::new(receiver);
+
+    let guard = {
+        let _context = runtime.enter();
+        listener.run_handler(Arc::new(PanickingHandler))
+    };
+
+    reporter.report_event(1usize).unwrap();
+
+    let token = reporter.disconnect().unwrap();
+    let err = HandlerGuard::join(guard, token).unwrap_err();
+
+    assert!(matches!(err, TokioJoinError::Panicked(panic) if panic.events_handled() == 0));
+}